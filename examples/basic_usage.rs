@@ -34,7 +34,9 @@ async fn main() -> Result<()> {
     // Show resource groups
     for (i, rg) in resource_groups.iter().enumerate() {
         println!("  {}. {} ({})", i + 1, rg.name, rg.location);
-        println!("     Status: {}", rg.properties.provisioning_state);
+        if let Some(state) = rg.provisioning_state() {
+            println!("     Status: {}", state);
+        }
     }
     
     // Get the first resource group
@@ -90,7 +92,9 @@ async fn main() -> Result<()> {
         match client.list_cosmos_keys(account_name, resource_group).await {
             Ok(keys) => {
                 println!("  ✅ Keys retrieved successfully");
-                println!("  🔐 Primary key: {}...", &keys.primary_master_key[..20]);
+                if let Some(key) = &keys.primary_master_key {
+                    println!("  🔐 Primary key: {}...", &key[..20]);
+                }
             }
             Err(e) => {
                 println!("  ❌ Error getting keys: {}", e);
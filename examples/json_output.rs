@@ -38,7 +38,7 @@ async fn main() -> Result<()> {
         println!("{}", rg_json);
         
         // Verify round-trip JSON conversion
-        let parsed_rg: rust_az_wrapper::commands::account::ResourceGroup = serde_json::from_str(&rg_json)?;
+        let parsed_rg: rust_az_wrapper::ResourceGroup = serde_json::from_str(&rg_json)?;
         println!("\n✅ Resource group JSON parsing successful!");
         println!("📍 Parsed RG name: {}", parsed_rg.name);
         println!("🌍 Parsed RG location: {}", parsed_rg.location);
@@ -73,7 +73,7 @@ async fn main() -> Result<()> {
                 // Verify keys parsing
                 let parsed_keys: CosmosKeys = serde_json::from_str(&keys_json)?;
                 println!("\n✅ Keys JSON parsing successful!");
-                println!("🔐 Has primary key: {}", !parsed_keys.primary_master_key.is_empty());
+                println!("🔐 Has primary key: {}", parsed_keys.primary_master_key.is_some());
             }
             Err(e) => {
                 println!("\n⚠️ Could not get keys (this is normal in some environments): {}", e);
@@ -88,7 +88,7 @@ async fn main() -> Result<()> {
         println!("{}", all_rgs_json);
         
         // Parse back
-        let parsed_rgs: Vec<rust_az_wrapper::commands::account::ResourceGroup> = serde_json::from_str(&all_rgs_json)?;
+        let parsed_rgs: Vec<rust_az_wrapper::ResourceGroup> = serde_json::from_str(&all_rgs_json)?;
         println!("\n✅ All resource groups JSON parsing successful!");
         println!("📊 Parsed {} resource groups", parsed_rgs.len());
     }
@@ -1,4 +1,8 @@
-use rust_az_wrapper::models::{CosmosAccount, BackupPolicy, ContinuousBackupProperties, PeriodicBackupProperties};
+use rust_az_wrapper::models::{
+    AccountKind, BackupPolicy, BackupType, ContinuousBackupProperties, ContinuousBackupTier, CosmosAccount,
+    PeriodicBackupProperties, ProvisioningState,
+};
+use rust_az_wrapper::AzureResourceId;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🔍 Validating Azure Cosmos DB specific models...\n");
@@ -23,30 +27,33 @@ fn test_continuous_backup_logic() -> Result<(), Box<dyn std::error::Error>> {
     
     // Simulate different continuous backup types
     let continuous_7_days = ContinuousBackupProperties {
-        tier: Some("Continuous7Days".to_string()),
+        tier: Some(ContinuousBackupTier::Continuous7Days),
+        extra: Default::default(),
     };
     
     let _continuous_30_days = ContinuousBackupProperties {
-        tier: Some("Continuous30Days".to_string()),
+        tier: Some(ContinuousBackupTier::Continuous30Days),
+        extra: Default::default(),
     };
     
     let backup_policy = BackupPolicy {
-        backup_type: "Continuous".to_string(),
+        backup_type: BackupType::Continuous,
         migration_state: None,
         periodic_mode_properties: None,
         continuous_mode_properties: Some(continuous_7_days),
+        extra: Default::default(),
     };
     
     // Validate our specific logic
-    assert_eq!(backup_policy.backup_type, "Continuous");
+    assert_eq!(backup_policy.backup_type, BackupType::Continuous);
     assert!(backup_policy.continuous_mode_properties.is_some());
     assert!(backup_policy.periodic_mode_properties.is_none());
     
-    if let Some(props) = &backup_policy.continuous_mode_properties {
-        if let Some(tier) = &props.tier {
-            assert!(tier.starts_with("Continuous"));
-            println!("   ✅ Valid continuous backup tier: {}", tier);
-        }
+    if let Some(props) = &backup_policy.continuous_mode_properties
+        && let Some(tier) = &props.tier
+    {
+        assert!(!matches!(tier, ContinuousBackupTier::Unknown(_)));
+        println!("   ✅ Valid continuous backup tier: {}", tier);
     }
     
     Ok(())
@@ -59,17 +66,19 @@ fn test_periodic_backup_logic() -> Result<(), Box<dyn std::error::Error>> {
         backup_interval_in_minutes: Some(240), // 4 hours
         backup_retention_interval_in_hours: Some(168), // 7 days
         backup_storage_redundancy: Some("LocallyRedundant".to_string()),
+        extra: Default::default(),
     };
     
     let backup_policy = BackupPolicy {
-        backup_type: "Periodic".to_string(),
+        backup_type: BackupType::Periodic,
         migration_state: None,
         periodic_mode_properties: Some(periodic_props),
         continuous_mode_properties: None,
+        extra: Default::default(),
     };
     
     // Validate our specific logic
-    assert_eq!(backup_policy.backup_type, "Periodic");
+    assert_eq!(backup_policy.backup_type, BackupType::Periodic);
     assert!(backup_policy.periodic_mode_properties.is_some());
     assert!(backup_policy.continuous_mode_properties.is_none());
     
@@ -112,8 +121,8 @@ fn test_azure_field_mapping() -> Result<(), Box<dyn std::error::Error>> {
     assert_eq!(account.name, "test-cosmos");
     assert_eq!(account.resource_group, "test-rg"); // Alias from "resourceGroup"
     assert_eq!(account.document_endpoint, "https://test-cosmos.documents.azure.com:443/");
-    assert_eq!(account.kind, "GlobalDocumentDB");
-    assert_eq!(account.provisioning_state, "Succeeded");
+    assert_eq!(account.kind, AccountKind::GlobalDocumentDB);
+    assert_eq!(account.provisioning_state, ProvisioningState::Succeeded);
     
     println!("   ✅ Field aliases working correctly");
     println!("   ✅ Azure CLI → Rust struct mapping validated");
@@ -135,21 +144,23 @@ fn demonstrate_practical_usage() -> Result<(), Box<dyn std::error::Error>> {
     // Create fictional account for demonstration
     let account = CosmosAccount {
         name: "my-app-cosmos".to_string(),
-        id: "/subscriptions/12345/resourceGroups/my-rg/providers/Microsoft.DocumentDB/databaseAccounts/my-app-cosmos".to_string(),
+        id: AzureResourceId::cosmos_account("12345", "my-rg", "my-app-cosmos"),
         location: "West Europe".to_string(),
         resource_group: "my-rg".to_string(),
         resource_type: "Microsoft.DocumentDB/databaseAccounts".to_string(),
-        kind: "MongoDB".to_string(),
-        provisioning_state: "Succeeded".to_string(),
+        kind: AccountKind::MongoDB,
+        provisioning_state: ProvisioningState::Succeeded,
         document_endpoint: "https://my-app-cosmos.documents.azure.com:443/".to_string(),
         tags: None,
         backup_policy: Some(BackupPolicy {
-            backup_type: "Continuous".to_string(),
+            backup_type: BackupType::Continuous,
             migration_state: None,
             continuous_mode_properties: Some(ContinuousBackupProperties {
-                tier: Some("Continuous7Days".to_string()),
+                tier: Some(ContinuousBackupTier::Continuous7Days),
+                extra: Default::default(),
             }),
             periodic_mode_properties: None,
+            extra: Default::default(),
         }),
         // ... other fields as None for simplicity
         analytical_storage_configuration: None,
@@ -190,20 +201,21 @@ fn demonstrate_practical_usage() -> Result<(), Box<dyn std::error::Error>> {
         system_data: None,
         virtual_network_rules: None,
         write_locations: None,
+        extra: Default::default(),
     };
     
     // Demonstrate domain-specific logic
     if let Some(backup) = &account.backup_policy {
-        match backup.backup_type.as_str() {
-            "Continuous" => {
+        match &backup.backup_type {
+            BackupType::Continuous => {
                 println!("   📊 Account with continuous backup configured");
-                if let Some(props) = &backup.continuous_mode_properties {
-                    if let Some(tier) = &props.tier {
-                        println!("   🕐 Tier: {}", tier);
-                    }
+                if let Some(props) = &backup.continuous_mode_properties
+                    && let Some(tier) = &props.tier
+                {
+                    println!("   🕐 Tier: {}", tier);
                 }
             },
-            "Periodic" => {
+            BackupType::Periodic => {
                 println!("   📊 Account with periodic backup configured");
             },
             _ => {
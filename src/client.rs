@@ -1,13 +1,20 @@
 //! Azure CLI client wrapper
 
 use crate::commands::{account, cosmos};
+use crate::executor::CommandExecutor;
+use crate::handles::{AccountRef, ContainerRef, DatabaseRef, MongoDatabaseRef};
 use crate::models::*;
-use crate::utils::check_authentication;
-use crate::Result;
+use crate::resource_id::AzureResourceId;
+use crate::retry::RetryPolicy;
+use crate::utils::{check_authentication, check_az_cli, ExecutionContext};
+use crate::{AzureError, Result};
+use std::time::Duration;
 
 /// Main client for Azure CLI operations
+#[derive(Debug, Clone)]
 pub struct AzureClient {
     subscription_id: Option<String>,
+    context: ExecutionContext,
 }
 
 impl AzureClient {
@@ -18,6 +25,7 @@ impl AzureClient {
         // Note: We can't use async in constructor, so authentication check is deferred
         Ok(Self {
             subscription_id: None,
+            context: ExecutionContext::default(),
         })
     }
 
@@ -25,12 +33,32 @@ impl AzureClient {
     pub fn with_subscription(subscription_id: String) -> Result<Self> {
         Ok(Self {
             subscription_id: Some(subscription_id),
+            context: ExecutionContext::default(),
         })
     }
 
+    /// Creates a new Azure client that dispatches commands to a custom executor
+    /// 
+    /// Useful for testing with a [`ScriptedExecutor`](crate::executor::ScriptedExecutor)
+    /// or plugging in an alternate backend.
+    pub fn with_executor<E>(executor: E) -> Result<Self>
+    where
+        E: CommandExecutor + 'static,
+    {
+        Ok(Self {
+            subscription_id: None,
+            context: ExecutionContext::new(executor),
+        })
+    }
+
+    /// Verifies that Azure CLI is installed, running `az --version` through the client's executor
+    pub async fn verify_cli(&self) -> Result<()> {
+        check_az_cli(&self.context).await
+    }
+
     /// Verifies authentication status
     pub async fn verify_authentication(&self) -> Result<()> {
-        check_authentication().await
+        check_authentication(&self.context).await
     }
 
     /// Sets the default subscription for all operations
@@ -43,103 +71,429 @@ impl AzureClient {
         self.subscription_id.as_deref()
     }
 
+    /// Replaces the executor used for all operations
+    pub fn set_executor<E>(&mut self, executor: E)
+    where
+        E: CommandExecutor + 'static,
+    {
+        self.context = self.context.clone().with_executor(executor);
+    }
+
+    /// Sets the default timeout for every Azure CLI invocation
+    /// 
+    /// Commands exceeding it are killed and fail with [`AzureError::Timeout`](crate::AzureError::Timeout).
+    /// `None` (the default) waits indefinitely.
+    pub fn set_default_timeout(&mut self, timeout: Option<Duration>) {
+        self.context = self.context.clone().with_timeout(timeout);
+    }
+
+    /// Gets the default timeout for every Azure CLI invocation
+    pub fn default_timeout(&self) -> Option<Duration> {
+        self.context.timeout()
+    }
+
+    /// Sets the policy used to retry throttled and transient failures
+    /// 
+    /// By default failures are not retried; `RetryPolicy::default()` gives three attempts
+    /// with exponential backoff.
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.context = self.context.clone().with_retry_policy(retry_policy);
+    }
+
+    /// Gets the policy used to retry throttled and transient failures
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        self.context.retry_policy()
+    }
+
+    /// Gets the execution context shared by all operations
+    pub fn context(&self) -> &ExecutionContext {
+        &self.context
+    }
+
     // === SUBSCRIPTION OPERATIONS (READ-ONLY) ===
 
     /// Lists all available subscriptions
     pub async fn list_subscriptions(&self) -> Result<Vec<Subscription>> {
-        account::AccountCommands::list_subscriptions().await
+        account::AccountCommands::list_subscriptions(&self.context).await
     }
 
     /// Shows the current subscription
     pub async fn show_current_subscription(&self) -> Result<Subscription> {
-        account::AccountCommands::show_subscription(None).await
+        account::AccountCommands::show_subscription(&self.context, None).await
     }
 
     /// Shows details of a specific subscription
     pub async fn show_subscription(&self, subscription_id: &str) -> Result<Subscription> {
-        account::AccountCommands::show_subscription(Some(subscription_id)).await
+        account::AccountCommands::show_subscription(&self.context, Some(subscription_id)).await
+    }
+
+    /// Lists the tenants the signed-in identity can access
+    pub async fn list_tenants(&self) -> Result<Vec<Tenant>> {
+        account::AccountCommands::list_tenants(&self.context).await
     }
 
     /// Lists available locations
-    pub async fn list_locations(&self) -> Result<Vec<serde_json::Value>> {
-        account::AccountCommands::list_locations(self.subscription_id.as_deref()).await
+    pub async fn list_locations(&self) -> Result<Vec<Location>> {
+        account::AccountCommands::list_locations(&self.context, self.subscription_id.as_deref()).await
     }
 
     /// Lists resource groups in current or specified subscription
-    pub async fn list_resource_groups(&self, subscription_id: Option<&str>) -> Result<Vec<account::ResourceGroup>> {
+    pub async fn list_resource_groups(&self, subscription_id: Option<&str>) -> Result<Vec<ResourceGroup>> {
         let sub_id = subscription_id.or(self.subscription_id.as_deref());
-        account::AccountCommands::list_resource_groups(sub_id).await
+        account::AccountCommands::list_resource_groups(&self.context, sub_id).await
     }
 
     /// Shows details of a specific resource group
-    pub async fn show_resource_group(&self, name: &str) -> Result<account::ResourceGroup> {
-        account::AccountCommands::show_resource_group(name, self.subscription_id.as_deref()).await
+    pub async fn show_resource_group(&self, name: &str) -> Result<ResourceGroup> {
+        account::AccountCommands::show_resource_group(&self.context, name, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists resource groups carrying a tag, optionally with a specific value
+    pub async fn list_resource_groups_by_tag(&self, key: &str, value: Option<&str>) -> Result<Vec<ResourceGroup>> {
+        account::AccountCommands::list_resource_groups_by_tag(&self.context, key, value, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists the resources in a resource group, optionally only those of one type
+    /// (e.g. `Microsoft.DocumentDB/databaseAccounts`)
+    pub async fn list_resources_in_group(&self, resource_group: &str, resource_type: Option<&str>) -> Result<Vec<GenericResource>> {
+        account::AccountCommands::list_resources_in_group(&self.context, resource_group, resource_type, self.subscription_id.as_deref()).await
     }
 
     // === COSMOS DB OPERATIONS (READ-ONLY) ===
 
+    /// Gets a handle to a Cosmos DB account, for navigating to its databases and containers
+    pub fn cosmos_account(&self, resource_group: &str, name: &str) -> AccountRef<'_> {
+        AccountRef::new(self, resource_group, name)
+    }
+
     /// Lists all Cosmos DB accounts
     pub async fn list_cosmos_accounts(&self, resource_group: Option<&str>) -> Result<Vec<CosmosAccount>> {
-        cosmos::list_accounts(resource_group, self.subscription_id.as_deref()).await
+        cosmos::list_accounts(&self.context, resource_group, self.subscription_id.as_deref()).await
     }
 
     /// Shows details of a specific Cosmos DB account
     pub async fn show_cosmos_account(&self, name: &str, resource_group: &str) -> Result<CosmosAccount> {
-        cosmos::show_account(name, resource_group, self.subscription_id.as_deref()).await
+        cosmos::show_account(&self.context, name, resource_group, self.subscription_id.as_deref()).await
     }
 
     /// Lists master keys for a Cosmos DB account
     pub async fn list_cosmos_keys(&self, name: &str, resource_group: &str) -> Result<CosmosKeys> {
-        cosmos::list_keys(name, resource_group, self.subscription_id.as_deref()).await
+        cosmos::list_keys(&self.context, name, resource_group, self.subscription_id.as_deref()).await
     }
 
     /// Lists read-only keys for a Cosmos DB account
     pub async fn list_cosmos_read_only_keys(&self, name: &str, resource_group: &str) -> Result<CosmosKeys> {
-        cosmos::list_read_only_keys(name, resource_group, self.subscription_id.as_deref()).await
+        cosmos::list_read_only_keys(&self.context, name, resource_group, self.subscription_id.as_deref()).await
     }
 
     /// Lists connection strings for a Cosmos DB account
     pub async fn list_cosmos_connection_strings(&self, name: &str, resource_group: &str) -> Result<CosmosConnectionStrings> {
-        cosmos::list_connection_strings(name, resource_group, self.subscription_id.as_deref()).await
+        cosmos::list_connection_strings(&self.context, name, resource_group, self.subscription_id.as_deref()).await
     }
 
     /// Lists SQL databases in a Cosmos DB account
     pub async fn list_sql_databases(&self, account_name: &str, resource_group: &str) -> Result<Vec<CosmosDatabase>> {
-        cosmos::list_sql_databases(account_name, resource_group, self.subscription_id.as_deref()).await
+        cosmos::list_sql_databases(&self.context, account_name, resource_group, self.subscription_id.as_deref()).await
     }
 
     /// Shows details of a specific SQL database
     pub async fn show_sql_database(&self, account_name: &str, resource_group: &str, database_name: &str) -> Result<CosmosDatabase> {
-        cosmos::show_sql_database(account_name, resource_group, database_name, self.subscription_id.as_deref()).await
+        cosmos::show_sql_database(&self.context, account_name, resource_group, database_name, self.subscription_id.as_deref()).await
     }
 
     /// Lists SQL containers in a database
     pub async fn list_sql_containers(&self, account_name: &str, resource_group: &str, database_name: &str) -> Result<Vec<CosmosContainer>> {
-        cosmos::list_sql_containers(account_name, resource_group, database_name, self.subscription_id.as_deref()).await
+        cosmos::list_sql_containers(&self.context, account_name, resource_group, database_name, self.subscription_id.as_deref()).await
     }
 
     /// Shows details of a specific SQL container
     pub async fn show_sql_container(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: &str) -> Result<CosmosContainer> {
-        cosmos::show_sql_container(account_name, resource_group, database_name, container_name, self.subscription_id.as_deref()).await
+        cosmos::show_sql_container(&self.context, account_name, resource_group, database_name, container_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists the stored procedures of a SQL container
+    pub async fn list_sql_stored_procedures(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: &str) -> Result<Vec<StoredProcedure>> {
+        cosmos::list_sql_stored_procedures(&self.context, account_name, resource_group, database_name, container_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows a specific stored procedure, including its body
+    pub async fn show_sql_stored_procedure(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: &str, procedure_name: &str) -> Result<StoredProcedure> {
+        cosmos::show_sql_stored_procedure(&self.context, account_name, resource_group, database_name, container_name, procedure_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists the triggers of a SQL container
+    pub async fn list_sql_triggers(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: &str) -> Result<Vec<Trigger>> {
+        cosmos::list_sql_triggers(&self.context, account_name, resource_group, database_name, container_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows a specific trigger, including its body
+    pub async fn show_sql_trigger(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: &str, trigger_name: &str) -> Result<Trigger> {
+        cosmos::show_sql_trigger(&self.context, account_name, resource_group, database_name, container_name, trigger_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists the user-defined functions of a SQL container
+    pub async fn list_sql_user_defined_functions(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: &str) -> Result<Vec<UserDefinedFunction>> {
+        cosmos::list_sql_user_defined_functions(&self.context, account_name, resource_group, database_name, container_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows a specific user-defined function, including its body
+    pub async fn show_sql_user_defined_function(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: &str, function_name: &str) -> Result<UserDefinedFunction> {
+        cosmos::show_sql_user_defined_function(&self.context, account_name, resource_group, database_name, container_name, function_name, self.subscription_id.as_deref()).await
     }
 
     /// Lists MongoDB databases in a Cosmos DB account
     pub async fn list_mongodb_databases(&self, account_name: &str, resource_group: &str) -> Result<Vec<CosmosDatabase>> {
-        cosmos::list_mongodb_databases(account_name, resource_group, self.subscription_id.as_deref()).await
+        cosmos::list_mongodb_databases(&self.context, account_name, resource_group, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows details of a specific MongoDB database
+    pub async fn show_mongodb_database(&self, account_name: &str, resource_group: &str, database_name: &str) -> Result<CosmosDatabase> {
+        cosmos::show_mongodb_database(&self.context, account_name, resource_group, database_name, self.subscription_id.as_deref()).await
     }
 
     /// Lists MongoDB collections in a database
-    pub async fn list_mongodb_collections(&self, account_name: &str, resource_group: &str, database_name: &str) -> Result<Vec<CosmosContainer>> {
-        cosmos::list_mongodb_collections(account_name, resource_group, database_name, self.subscription_id.as_deref()).await
+    pub async fn list_mongodb_collections(&self, account_name: &str, resource_group: &str, database_name: &str) -> Result<Vec<MongoCollection>> {
+        cosmos::list_mongodb_collections(&self.context, account_name, resource_group, database_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows details of a specific MongoDB collection
+    pub async fn show_mongodb_collection(&self, account_name: &str, resource_group: &str, database_name: &str, collection_name: &str) -> Result<MongoCollection> {
+        cosmos::show_mongodb_collection(&self.context, account_name, resource_group, database_name, collection_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists Cassandra keyspaces in a Cosmos DB account
+    pub async fn list_cassandra_keyspaces(&self, account_name: &str, resource_group: &str) -> Result<Vec<CosmosDatabase>> {
+        cosmos::list_cassandra_keyspaces(&self.context, account_name, resource_group, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows details of a specific Cassandra keyspace
+    pub async fn show_cassandra_keyspace(&self, account_name: &str, resource_group: &str, keyspace_name: &str) -> Result<CosmosDatabase> {
+        cosmos::show_cassandra_keyspace(&self.context, account_name, resource_group, keyspace_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists Cassandra tables in a keyspace
+    pub async fn list_cassandra_tables(&self, account_name: &str, resource_group: &str, keyspace_name: &str) -> Result<Vec<CassandraTable>> {
+        cosmos::list_cassandra_tables(&self.context, account_name, resource_group, keyspace_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows details of a specific Cassandra table
+    pub async fn show_cassandra_table(&self, account_name: &str, resource_group: &str, keyspace_name: &str, table_name: &str) -> Result<CassandraTable> {
+        cosmos::show_cassandra_table(&self.context, account_name, resource_group, keyspace_name, table_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists Gremlin databases in a Cosmos DB account
+    pub async fn list_gremlin_databases(&self, account_name: &str, resource_group: &str) -> Result<Vec<CosmosDatabase>> {
+        cosmos::list_gremlin_databases(&self.context, account_name, resource_group, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows details of a specific Gremlin database
+    pub async fn show_gremlin_database(&self, account_name: &str, resource_group: &str, database_name: &str) -> Result<CosmosDatabase> {
+        cosmos::show_gremlin_database(&self.context, account_name, resource_group, database_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists Gremlin graphs in a database
+    pub async fn list_gremlin_graphs(&self, account_name: &str, resource_group: &str, database_name: &str) -> Result<Vec<CosmosContainer>> {
+        cosmos::list_gremlin_graphs(&self.context, account_name, resource_group, database_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows details of a specific Gremlin graph
+    pub async fn show_gremlin_graph(&self, account_name: &str, resource_group: &str, database_name: &str, graph_name: &str) -> Result<CosmosContainer> {
+        cosmos::show_gremlin_graph(&self.context, account_name, resource_group, database_name, graph_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists Table API tables in a Cosmos DB account
+    pub async fn list_cosmos_tables(&self, account_name: &str, resource_group: &str) -> Result<Vec<CosmosTable>> {
+        cosmos::list_tables(&self.context, account_name, resource_group, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows details of a specific Table API table
+    pub async fn show_cosmos_table(&self, account_name: &str, resource_group: &str, table_name: &str) -> Result<CosmosTable> {
+        cosmos::show_table(&self.context, account_name, resource_group, table_name, self.subscription_id.as_deref()).await
     }
 
-    /// Gets throughput settings of a database
+    /// Gets throughput settings of a SQL database
     pub async fn get_database_throughput(&self, account_name: &str, resource_group: &str, database_name: &str) -> Result<ThroughputSettings> {
-        cosmos::get_database_throughput(account_name, resource_group, database_name, self.subscription_id.as_deref()).await
+        cosmos::get_database_throughput(&self.context, account_name, resource_group, database_name, self.subscription_id.as_deref()).await
     }
 
-    /// Gets throughput settings of a container
+    /// Gets throughput settings of a SQL container
     pub async fn get_container_throughput(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: &str) -> Result<ThroughputSettings> {
-        cosmos::get_container_throughput(account_name, resource_group, database_name, container_name, self.subscription_id.as_deref()).await
+        cosmos::get_container_throughput(&self.context, account_name, resource_group, database_name, container_name, self.subscription_id.as_deref()).await
     }
-} 
\ No newline at end of file
+
+    /// Gets throughput settings of a MongoDB database
+    pub async fn get_mongodb_database_throughput(&self, account_name: &str, resource_group: &str, database_name: &str) -> Result<ThroughputSettings> {
+        cosmos::get_mongodb_database_throughput(&self.context, account_name, resource_group, database_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Gets throughput settings of a MongoDB collection
+    pub async fn get_mongodb_collection_throughput(&self, account_name: &str, resource_group: &str, database_name: &str, collection_name: &str) -> Result<ThroughputSettings> {
+        cosmos::get_mongodb_collection_throughput(&self.context, account_name, resource_group, database_name, collection_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Gets throughput settings of a Cassandra keyspace
+    pub async fn get_cassandra_keyspace_throughput(&self, account_name: &str, resource_group: &str, keyspace_name: &str) -> Result<ThroughputSettings> {
+        cosmos::get_cassandra_keyspace_throughput(&self.context, account_name, resource_group, keyspace_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Gets throughput settings of a Cassandra table
+    pub async fn get_cassandra_table_throughput(&self, account_name: &str, resource_group: &str, keyspace_name: &str, table_name: &str) -> Result<ThroughputSettings> {
+        cosmos::get_cassandra_table_throughput(&self.context, account_name, resource_group, keyspace_name, table_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Gets throughput settings of a Gremlin database
+    pub async fn get_gremlin_database_throughput(&self, account_name: &str, resource_group: &str, database_name: &str) -> Result<ThroughputSettings> {
+        cosmos::get_gremlin_database_throughput(&self.context, account_name, resource_group, database_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Gets throughput settings of a Gremlin graph
+    pub async fn get_gremlin_graph_throughput(&self, account_name: &str, resource_group: &str, database_name: &str, graph_name: &str) -> Result<ThroughputSettings> {
+        cosmos::get_gremlin_graph_throughput(&self.context, account_name, resource_group, database_name, graph_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Gets throughput settings of a Table API table
+    pub async fn get_cosmos_table_throughput(&self, account_name: &str, resource_group: &str, table_name: &str) -> Result<ThroughputSettings> {
+        cosmos::get_table_throughput(&self.context, account_name, resource_group, table_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Gets the throughput serving a database or container of an account of any API
+    ///
+    /// Calls the `az cosmosdb sql|mongodb|cassandra|gremlin|table` command group matching the
+    /// account's kind and capabilities, in the subscription the account was listed from.
+    /// `database_name` names the database or keyspace, or the table for Table API accounts.
+    pub async fn get_throughput(&self, account: &CosmosAccount, database_name: &str, container_name: Option<&str>) -> Result<ProvisionedThroughput> {
+        cosmos::get_provisioned_throughput(&self.context, account, database_name, container_name).await
+    }
+
+    /// Lists the SQL data-plane role definitions of an account, built-in and custom
+    pub async fn list_sql_role_definitions(&self, account_name: &str, resource_group: &str) -> Result<Vec<SqlRoleDefinition>> {
+        cosmos::list_sql_role_definitions(&self.context, account_name, resource_group, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows a SQL data-plane role definition by GUID or ID
+    pub async fn show_sql_role_definition(&self, account_name: &str, resource_group: &str, role_definition_id: &str) -> Result<SqlRoleDefinition> {
+        cosmos::show_sql_role_definition(&self.context, account_name, resource_group, role_definition_id, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists the SQL data-plane role assignments of an account
+    pub async fn list_sql_role_assignments(&self, account_name: &str, resource_group: &str) -> Result<Vec<SqlRoleAssignment>> {
+        cosmos::list_sql_role_assignments(&self.context, account_name, resource_group, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows a SQL data-plane role assignment by GUID or ID
+    pub async fn show_sql_role_assignment(&self, account_name: &str, resource_group: &str, role_assignment_id: &str) -> Result<SqlRoleAssignment> {
+        cosmos::show_sql_role_assignment(&self.context, account_name, resource_group, role_assignment_id, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists the MongoDB role definitions of an account
+    pub async fn list_mongodb_role_definitions(&self, account_name: &str, resource_group: &str) -> Result<Vec<MongoRoleDefinition>> {
+        cosmos::list_mongodb_role_definitions(&self.context, account_name, resource_group, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows a MongoDB role definition by ID (`{database}.{role}`)
+    pub async fn show_mongodb_role_definition(&self, account_name: &str, resource_group: &str, role_definition_id: &str) -> Result<MongoRoleDefinition> {
+        cosmos::show_mongodb_role_definition(&self.context, account_name, resource_group, role_definition_id, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists the MongoDB user definitions of an account
+    pub async fn list_mongodb_user_definitions(&self, account_name: &str, resource_group: &str) -> Result<Vec<MongoUserDefinition>> {
+        cosmos::list_mongodb_user_definitions(&self.context, account_name, resource_group, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows a MongoDB user definition by ID (`{database}.{user}`)
+    pub async fn show_mongodb_user_definition(&self, account_name: &str, resource_group: &str, user_definition_id: &str) -> Result<MongoUserDefinition> {
+        cosmos::show_mongodb_user_definition(&self.context, account_name, resource_group, user_definition_id, self.subscription_id.as_deref()).await
+    }
+
+    /// Resolves which principals can read or write items in a SQL database or container
+    pub async fn resolve_sql_data_access(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: Option<&str>) -> Result<Vec<DataPlaneAccess>> {
+        cosmos::resolve_sql_data_access(&self.context, account_name, resource_group, database_name, container_name, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists restorable (live and deleted) accounts with continuous backup, optionally by name or region
+    pub async fn list_restorable_database_accounts(&self, account_name: Option<&str>, location: Option<&str>) -> Result<Vec<RestorableDatabaseAccount>> {
+        cosmos::list_restorable_database_accounts(&self.context, account_name, location, self.subscription_id.as_deref()).await
+    }
+
+    /// Shows a restorable account by instance ID
+    pub async fn show_restorable_database_account(&self, instance_id: &str, location: &str) -> Result<RestorableDatabaseAccount> {
+        cosmos::show_restorable_database_account(&self.context, instance_id, location, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists the creation and deletion events of the SQL databases of a restorable account
+    pub async fn list_restorable_sql_databases(&self, instance_id: &str, location: &str) -> Result<Vec<RestorableSqlDatabase>> {
+        cosmos::list_restorable_sql_databases(&self.context, instance_id, location, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists the events of the SQL containers of a restorable database, optionally within a time range
+    pub async fn list_restorable_sql_containers(&self, instance_id: &str, location: &str, database_rid: &str, start_time: Option<&str>, end_time: Option<&str>) -> Result<Vec<RestorableSqlContainer>> {
+        cosmos::list_restorable_sql_containers(&self.context, instance_id, location, database_rid, start_time, end_time, self.subscription_id.as_deref()).await
+    }
+
+    /// Lists the SQL databases and containers that can be restored to a point in time
+    pub async fn list_restorable_sql_resources(&self, instance_id: &str, location: &str, restore_location: &str, restore_timestamp: &str) -> Result<Vec<RestorableSqlResource>> {
+        cosmos::list_restorable_sql_resources(&self.context, instance_id, location, restore_location, restore_timestamp, self.subscription_id.as_deref()).await
+    }
+
+    /// Gets the latest restorable backup time of a SQL container in a region
+    pub async fn retrieve_latest_sql_backup_time(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: &str, location: &str) -> Result<BackupInformation> {
+        cosmos::retrieve_latest_sql_backup_time(&self.context, account_name, resource_group, database_name, container_name, location, self.subscription_id.as_deref()).await
+    }
+
+    /// Computes the earliest and latest timestamps a SQL container can be restored to, or `None` if it has no restorable point
+    pub async fn get_sql_container_restore_window(&self, account_name: &str, resource_group: &str, database_name: &str, container_name: &str, location: &str) -> Result<Option<RestoreWindow>> {
+        cosmos::get_sql_container_restore_window(&self.context, account_name, resource_group, database_name, container_name, location, self.subscription_id.as_deref()).await
+    }
+
+    // === RESOURCE ID OPERATIONS (READ-ONLY) ===
+    //
+    // Accept an ARM resource ID instead of separate names. The subscription in the ID
+    // takes precedence over the client's subscription.
+
+    /// Shows a resource group from its resource ID
+    pub async fn show_resource_group_by_id(&self, id: &AzureResourceId) -> Result<ResourceGroup> {
+        if id.resource_type().is_some() {
+            return Err(AzureError::InvalidResourceId {
+                id: id.to_string(),
+                reason: "expected a resource group".to_string(),
+            });
+        }
+        let name = id.require_segment("resourceGroups")?;
+        account::AccountCommands::show_resource_group(&self.context, name, id.subscription_id()).await
+    }
+
+    /// Shows a Cosmos DB account from its resource ID
+    pub async fn show_cosmos_account_by_id(&self, id: &AzureResourceId) -> Result<CosmosAccount> {
+        AccountRef::from_id(self, id)?.show().await
+    }
+
+    /// Lists SQL databases of the Cosmos DB account identified by `account_id`
+    pub async fn list_sql_databases_by_id(&self, account_id: &AzureResourceId) -> Result<Vec<CosmosDatabase>> {
+        AccountRef::from_id(self, account_id)?.sql_databases().await
+    }
+
+    /// Shows a SQL database from its resource ID
+    pub async fn show_sql_database_by_id(&self, id: &AzureResourceId) -> Result<CosmosDatabase> {
+        DatabaseRef::from_id(self, id)?.show().await
+    }
+
+    /// Lists SQL containers of the database identified by `database_id`
+    pub async fn list_sql_containers_by_id(&self, database_id: &AzureResourceId) -> Result<Vec<CosmosContainer>> {
+        DatabaseRef::from_id(self, database_id)?.containers().await
+    }
+
+    /// Shows a SQL container from its resource ID
+    pub async fn show_sql_container_by_id(&self, id: &AzureResourceId) -> Result<CosmosContainer> {
+        ContainerRef::from_id(self, id)?.show().await
+    }
+
+    /// Lists MongoDB databases of the Cosmos DB account identified by `account_id`
+    pub async fn list_mongodb_databases_by_id(&self, account_id: &AzureResourceId) -> Result<Vec<CosmosDatabase>> {
+        AccountRef::from_id(self, account_id)?.mongodb_databases().await
+    }
+
+    /// Lists MongoDB collections of the database identified by `database_id`
+    pub async fn list_mongodb_collections_by_id(&self, database_id: &AzureResourceId) -> Result<Vec<MongoCollection>> {
+        MongoDatabaseRef::from_id(self, database_id)?.collections().await
+    }
+}
@@ -1,43 +1,16 @@
 //! Commands related to Azure accounts and subscriptions
 
 use crate::error::Result;
-use crate::models::Subscription;
-use crate::utils::AzCommandBuilder;
-use serde::{Deserialize, Serialize};
-
-/// Resource Group properties
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ResourceGroupProperties {
-    /// Provisioning status
-    #[serde(alias = "provisioningState")]
-    pub provisioning_state: String,
-}
-
-/// Resource Group information
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ResourceGroup {
-    /// Resource group name
-    pub name: String,
-    /// Resource group location
-    pub location: String,
-    /// Resource group properties
-    pub properties: ResourceGroupProperties,
-    /// Associated tags
-    pub tags: Option<std::collections::HashMap<String, String>>,
-    /// Resource group ID
-    pub id: String,
-    /// Managed by (if applicable)
-    #[serde(alias = "managedBy")]
-    pub managed_by: Option<String>,
-}
+use crate::models::{GenericResource, Location, ResourceGroup, Subscription, Tenant};
+use crate::utils::{AzCommandBuilder, ExecutionContext};
 
 /// Commands for managing accounts and subscriptions
 pub struct AccountCommands;
 
 impl AccountCommands {
     /// Lists all available subscriptions
-    pub async fn list_subscriptions() -> Result<Vec<Subscription>> {
-        AzCommandBuilder::new()
+    pub async fn list_subscriptions(context: &ExecutionContext) -> Result<Vec<Subscription>> {
+        AzCommandBuilder::with_context(context)
             .subcommand("account")
             .subcommand("list")
             .execute()
@@ -45,8 +18,8 @@ impl AccountCommands {
     }
 
     /// Gets information about the current or specific subscription
-    pub async fn show_subscription(subscription_id: Option<&str>) -> Result<Subscription> {
-        AzCommandBuilder::new()
+    pub async fn show_subscription(context: &ExecutionContext, subscription_id: Option<&str>) -> Result<Subscription> {
+        AzCommandBuilder::with_context(context)
             .subcommand("account")
             .subcommand("show")
             .subscription(subscription_id)
@@ -54,9 +27,19 @@ impl AccountCommands {
             .await
     }
 
+    /// Lists the tenants the signed-in identity can access
+    pub async fn list_tenants(context: &ExecutionContext) -> Result<Vec<Tenant>> {
+        AzCommandBuilder::with_context(context)
+            .subcommand("account")
+            .subcommand("tenant")
+            .subcommand("list")
+            .execute()
+            .await
+    }
+
     /// Sets a subscription as default
-    pub async fn set_subscription(subscription_id: &str) -> Result<String> {
-        AzCommandBuilder::new()
+    pub async fn set_subscription(context: &ExecutionContext, subscription_id: &str) -> Result<String> {
+        AzCommandBuilder::with_context(context)
             .subcommand("account")
             .subcommand("set")
             .param("--subscription", subscription_id)
@@ -65,8 +48,8 @@ impl AccountCommands {
     }
 
     /// Lists all available locations
-    pub async fn list_locations(subscription_id: Option<&str>) -> Result<Vec<serde_json::Value>> {
-        AzCommandBuilder::new()
+    pub async fn list_locations(context: &ExecutionContext, subscription_id: Option<&str>) -> Result<Vec<Location>> {
+        AzCommandBuilder::with_context(context)
             .subcommand("account")
             .subcommand("list-locations")
             .subscription(subscription_id)
@@ -75,8 +58,8 @@ impl AccountCommands {
     }
 
     /// Lists all resource groups
-    pub async fn list_resource_groups(subscription_id: Option<&str>) -> Result<Vec<ResourceGroup>> {
-        AzCommandBuilder::new()
+    pub async fn list_resource_groups(context: &ExecutionContext, subscription_id: Option<&str>) -> Result<Vec<ResourceGroup>> {
+        AzCommandBuilder::with_context(context)
             .subcommand("group")
             .subcommand("list")
             .subscription(subscription_id)
@@ -84,9 +67,36 @@ impl AccountCommands {
             .await
     }
 
+    /// Lists resource groups carrying a tag, optionally with a specific value
+    pub async fn list_resource_groups_by_tag(context: &ExecutionContext, key: &str, value: Option<&str>, subscription_id: Option<&str>) -> Result<Vec<ResourceGroup>> {
+        let tag = match value {
+            Some(value) => format!("{}={}", key, value),
+            None => key.to_string(),
+        };
+        AzCommandBuilder::with_context(context)
+            .subcommand("group")
+            .subcommand("list")
+            .param("--tag", &tag)
+            .subscription(subscription_id)
+            .execute()
+            .await
+    }
+
+    /// Lists the resources in a resource group, optionally only those of one type
+    pub async fn list_resources_in_group(context: &ExecutionContext, resource_group: &str, resource_type: Option<&str>, subscription_id: Option<&str>) -> Result<Vec<GenericResource>> {
+        AzCommandBuilder::with_context(context)
+            .subcommand("resource")
+            .subcommand("list")
+            .param("--resource-group", resource_group)
+            .optional_param("--resource-type", &resource_type.map(str::to_string))
+            .subscription(subscription_id)
+            .execute()
+            .await
+    }
+
     /// Shows details of a specific resource group
-    pub async fn show_resource_group(name: &str, subscription_id: Option<&str>) -> Result<ResourceGroup> {
-        AzCommandBuilder::new()
+    pub async fn show_resource_group(context: &ExecutionContext, name: &str, subscription_id: Option<&str>) -> Result<ResourceGroup> {
+        AzCommandBuilder::with_context(context)
             .subcommand("group")
             .subcommand("show")
             .param("--name", name)
@@ -96,8 +106,8 @@ impl AccountCommands {
     }
 
     /// Creates a new resource group
-    pub async fn create_resource_group(name: &str, location: &str, subscription_id: Option<&str>) -> Result<ResourceGroup> {
-        AzCommandBuilder::new()
+    pub async fn create_resource_group(context: &ExecutionContext, name: &str, location: &str, subscription_id: Option<&str>) -> Result<ResourceGroup> {
+        AzCommandBuilder::with_context(context)
             .subcommand("group")
             .subcommand("create")
             .param("--name", name)
@@ -108,8 +118,8 @@ impl AccountCommands {
     }
 
     /// Deletes a resource group
-    pub async fn delete_resource_group(name: &str, subscription_id: Option<&str>) -> Result<String> {
-        AzCommandBuilder::new()
+    pub async fn delete_resource_group(context: &ExecutionContext, name: &str, subscription_id: Option<&str>) -> Result<String> {
+        AzCommandBuilder::with_context(context)
             .subcommand("group")
             .subcommand("delete")
             .param("--name", name)
@@ -4,17 +4,18 @@
 //! Only query operations are supported - no creation, modification, or deletion.
 
 use crate::models::*;
-use crate::utils::AzCommandBuilder;
-use crate::Result;
+use crate::utils::{AzCommandBuilder, ExecutionContext};
+use crate::{AzureError, CliErrorDetails, Result};
 
 // === ACCOUNT COMMANDS (READ-ONLY) ===
 
 /// Lists all Cosmos DB accounts in a resource group or subscription
 pub async fn list_accounts(
+    context: &ExecutionContext,
     resource_group: Option<&str>,
     subscription_id: Option<&str>
 ) -> Result<Vec<CosmosAccount>> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("list");
     
@@ -31,11 +32,12 @@ pub async fn list_accounts(
 
 /// Shows details of a specific Cosmos DB account
 pub async fn show_account(
+    context: &ExecutionContext,
     name: &str, 
     resource_group: &str,
     subscription_id: Option<&str>
 ) -> Result<CosmosAccount> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("show")
         .param("--name", name)
@@ -52,11 +54,12 @@ pub async fn show_account(
 
 /// Lists master keys for a Cosmos DB account
 pub async fn list_keys(
+    context: &ExecutionContext,
     name: &str,
     resource_group: &str,
     subscription_id: Option<&str>
 ) -> Result<CosmosKeys> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("keys")
         .subcommand("list")
@@ -72,11 +75,12 @@ pub async fn list_keys(
 
 /// Lists read-only keys for a Cosmos DB account
 pub async fn list_read_only_keys(
+    context: &ExecutionContext,
     name: &str,
     resource_group: &str,
     subscription_id: Option<&str>
 ) -> Result<CosmosKeys> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("keys")
         .subcommand("list")
@@ -93,11 +97,12 @@ pub async fn list_read_only_keys(
 
 /// Lists connection strings for a Cosmos DB account
 pub async fn list_connection_strings(
+    context: &ExecutionContext,
     name: &str,
     resource_group: &str,
     subscription_id: Option<&str>
 ) -> Result<CosmosConnectionStrings> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("keys")
         .subcommand("list")
@@ -116,11 +121,12 @@ pub async fn list_connection_strings(
 
 /// Lists SQL databases in a Cosmos DB account
 pub async fn list_sql_databases(
+    context: &ExecutionContext,
     account_name: &str,
     resource_group: &str,
     subscription_id: Option<&str>
 ) -> Result<Vec<CosmosDatabase>> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("sql")
         .subcommand("database")
@@ -137,12 +143,13 @@ pub async fn list_sql_databases(
 
 /// Shows details of a specific SQL database
 pub async fn show_sql_database(
+    context: &ExecutionContext,
     account_name: &str,
     resource_group: &str,
     database_name: &str,
     subscription_id: Option<&str>
 ) -> Result<CosmosDatabase> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("sql")
         .subcommand("database")
@@ -162,12 +169,13 @@ pub async fn show_sql_database(
 
 /// Lists SQL containers in a database
 pub async fn list_sql_containers(
+    context: &ExecutionContext,
     account_name: &str,
     resource_group: &str,
     database_name: &str,
     subscription_id: Option<&str>
 ) -> Result<Vec<CosmosContainer>> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("sql")
         .subcommand("container")
@@ -185,13 +193,14 @@ pub async fn list_sql_containers(
 
 /// Shows details of a specific SQL container
 pub async fn show_sql_container(
+    context: &ExecutionContext,
     account_name: &str,
     resource_group: &str,
     database_name: &str,
     container_name: &str,
     subscription_id: Option<&str>
 ) -> Result<CosmosContainer> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("sql")
         .subcommand("container")
@@ -208,21 +217,791 @@ pub async fn show_sql_container(
     builder.execute().await
 }
 
+// === SQL SERVER-SIDE CODE COMMANDS (READ-ONLY) ===
+
+/// Lists the stored procedures of a SQL container
+pub async fn list_sql_stored_procedures(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    container_name: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<StoredProcedure>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("stored-procedure")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name)
+        .param("--container-name", container_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows a specific stored procedure, including its body
+pub async fn show_sql_stored_procedure(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    container_name: &str,
+    procedure_name: &str,
+    subscription_id: Option<&str>
+) -> Result<StoredProcedure> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("stored-procedure")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name)
+        .param("--container-name", container_name)
+        .param("--name", procedure_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Lists the triggers of a SQL container
+pub async fn list_sql_triggers(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    container_name: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<Trigger>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("trigger")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name)
+        .param("--container-name", container_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows a specific trigger, including its body
+pub async fn show_sql_trigger(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    container_name: &str,
+    trigger_name: &str,
+    subscription_id: Option<&str>
+) -> Result<Trigger> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("trigger")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name)
+        .param("--container-name", container_name)
+        .param("--name", trigger_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Lists the user-defined functions of a SQL container
+pub async fn list_sql_user_defined_functions(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    container_name: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<UserDefinedFunction>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("user-defined-function")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name)
+        .param("--container-name", container_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows a specific user-defined function, including its body
+pub async fn show_sql_user_defined_function(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    container_name: &str,
+    function_name: &str,
+    subscription_id: Option<&str>
+) -> Result<UserDefinedFunction> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("user-defined-function")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name)
+        .param("--container-name", container_name)
+        .param("--name", function_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
 // === MONGODB COMMANDS (READ-ONLY) ===
 
-/// Lists MongoDB databases in a Cosmos DB account
-pub async fn list_mongodb_databases(
+/// Lists MongoDB databases in a Cosmos DB account
+pub async fn list_mongodb_databases(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<CosmosDatabase>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("mongodb")
+        .subcommand("database")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows details of a specific MongoDB database
+pub async fn show_mongodb_database(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    subscription_id: Option<&str>
+) -> Result<CosmosDatabase> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("mongodb")
+        .subcommand("database")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--name", database_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Lists MongoDB collections in a database
+pub async fn list_mongodb_collections(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<MongoCollection>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("mongodb")
+        .subcommand("collection")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows details of a specific MongoDB collection
+pub async fn show_mongodb_collection(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    collection_name: &str,
+    subscription_id: Option<&str>
+) -> Result<MongoCollection> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("mongodb")
+        .subcommand("collection")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name)
+        .param("--name", collection_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+// === CASSANDRA COMMANDS (READ-ONLY) ===
+
+/// Lists Cassandra keyspaces in a Cosmos DB account
+pub async fn list_cassandra_keyspaces(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<CosmosDatabase>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("cassandra")
+        .subcommand("keyspace")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows details of a specific Cassandra keyspace
+pub async fn show_cassandra_keyspace(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    keyspace_name: &str,
+    subscription_id: Option<&str>
+) -> Result<CosmosDatabase> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("cassandra")
+        .subcommand("keyspace")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--name", keyspace_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Lists Cassandra tables in a keyspace
+pub async fn list_cassandra_tables(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    keyspace_name: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<CassandraTable>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("cassandra")
+        .subcommand("table")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--keyspace-name", keyspace_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows details of a specific Cassandra table
+pub async fn show_cassandra_table(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    keyspace_name: &str,
+    table_name: &str,
+    subscription_id: Option<&str>
+) -> Result<CassandraTable> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("cassandra")
+        .subcommand("table")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--keyspace-name", keyspace_name)
+        .param("--name", table_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+// === GREMLIN COMMANDS (READ-ONLY) ===
+
+/// Lists Gremlin databases in a Cosmos DB account
+pub async fn list_gremlin_databases(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<CosmosDatabase>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("gremlin")
+        .subcommand("database")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows details of a specific Gremlin database
+pub async fn show_gremlin_database(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    subscription_id: Option<&str>
+) -> Result<CosmosDatabase> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("gremlin")
+        .subcommand("database")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--name", database_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Lists Gremlin graphs in a database
+pub async fn list_gremlin_graphs(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<CosmosContainer>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("gremlin")
+        .subcommand("graph")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows details of a specific Gremlin graph
+pub async fn show_gremlin_graph(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    graph_name: &str,
+    subscription_id: Option<&str>
+) -> Result<CosmosContainer> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("gremlin")
+        .subcommand("graph")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name)
+        .param("--name", graph_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+// === TABLE COMMANDS (READ-ONLY) ===
+
+/// Lists Table API tables in a Cosmos DB account
+pub async fn list_tables(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<CosmosTable>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("table")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows details of a specific Table API table
+pub async fn show_table(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    table_name: &str,
+    subscription_id: Option<&str>
+) -> Result<CosmosTable> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("table")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--name", table_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+// === DATA-PLANE RBAC COMMANDS (READ-ONLY) ===
+
+/// Lists the SQL data-plane role definitions of an account, built-in and custom
+pub async fn list_sql_role_definitions(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<SqlRoleDefinition>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("role")
+        .subcommand("definition")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows a SQL data-plane role definition by GUID or ID
+pub async fn show_sql_role_definition(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    role_definition_id: &str,
+    subscription_id: Option<&str>
+) -> Result<SqlRoleDefinition> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("role")
+        .subcommand("definition")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--id", role_definition_id);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Lists the SQL data-plane role assignments of an account
+pub async fn list_sql_role_assignments(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<SqlRoleAssignment>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("role")
+        .subcommand("assignment")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows a SQL data-plane role assignment by GUID or ID
+pub async fn show_sql_role_assignment(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    role_assignment_id: &str,
+    subscription_id: Option<&str>
+) -> Result<SqlRoleAssignment> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("role")
+        .subcommand("assignment")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--role-assignment-id", role_assignment_id);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Lists the MongoDB role definitions of an account
+pub async fn list_mongodb_role_definitions(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<MongoRoleDefinition>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("mongodb")
+        .subcommand("role")
+        .subcommand("definition")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows a MongoDB role definition by ID (`{database}.{role}`)
+pub async fn show_mongodb_role_definition(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    role_definition_id: &str,
+    subscription_id: Option<&str>
+) -> Result<MongoRoleDefinition> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("mongodb")
+        .subcommand("role")
+        .subcommand("definition")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--id", role_definition_id);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Lists the MongoDB user definitions of an account
+pub async fn list_mongodb_user_definitions(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<MongoUserDefinition>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("mongodb")
+        .subcommand("user")
+        .subcommand("definition")
+        .subcommand("list")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows a MongoDB user definition by ID (`{database}.{user}`)
+pub async fn show_mongodb_user_definition(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    user_definition_id: &str,
+    subscription_id: Option<&str>
+) -> Result<MongoUserDefinition> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("mongodb")
+        .subcommand("user")
+        .subcommand("definition")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--id", user_definition_id);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Resolves which principals can read or write items in a SQL database or container
+///
+/// Lists the account's role definitions and assignments and combines the assignments at the
+/// scope and its parents; see [`DataPlaneAccess::resolve`].
+pub async fn resolve_sql_data_access(
+    context: &ExecutionContext,
     account_name: &str,
     resource_group: &str,
+    database_name: &str,
+    container_name: Option<&str>,
     subscription_id: Option<&str>
-) -> Result<Vec<CosmosDatabase>> {
-    let mut builder = AzCommandBuilder::new()
+) -> Result<Vec<DataPlaneAccess>> {
+    let definitions = list_sql_role_definitions(context, account_name, resource_group, subscription_id).await?;
+    let assignments = list_sql_role_assignments(context, account_name, resource_group, subscription_id).await?;
+
+    let mut scope = format!("/dbs/{}", database_name);
+    if let Some(container) = container_name {
+        scope.push_str(&format!("/colls/{}", container));
+    }
+
+    Ok(DataPlaneAccess::resolve(&definitions, &assignments, &scope))
+}
+
+// === RESTORE DISCOVERY COMMANDS (READ-ONLY) ===
+
+/// Lists restorable (live and deleted) accounts with continuous backup
+pub async fn list_restorable_database_accounts(
+    context: &ExecutionContext,
+    account_name: Option<&str>,
+    location: Option<&str>,
+    subscription_id: Option<&str>
+) -> Result<Vec<RestorableDatabaseAccount>> {
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
-        .subcommand("mongodb")
-        .subcommand("database")
+        .subcommand("restorable-database-account")
+        .subcommand("list");
+    
+    if let Some(name) = account_name {
+        builder = builder.param("--account-name", name);
+    }
+    
+    if let Some(location) = location {
+        builder = builder.param("--location", location);
+    }
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Shows a restorable account by instance ID
+pub async fn show_restorable_database_account(
+    context: &ExecutionContext,
+    instance_id: &str,
+    location: &str,
+    subscription_id: Option<&str>
+) -> Result<RestorableDatabaseAccount> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("restorable-database-account")
+        .subcommand("show")
+        .param("--instance-id", instance_id)
+        .param("--location", location);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Lists the creation and deletion events of the SQL databases of a restorable account
+pub async fn list_restorable_sql_databases(
+    context: &ExecutionContext,
+    instance_id: &str,
+    location: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<RestorableSqlDatabase>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("restorable-database")
         .subcommand("list")
-        .param("--account-name", account_name)
-        .param("--resource-group", resource_group);
+        .param("--instance-id", instance_id)
+        .param("--location", location);
     
     if let Some(sub) = subscription_id {
         builder = builder.subscription(Some(sub));
@@ -231,21 +1010,85 @@ pub async fn list_mongodb_databases(
     builder.execute().await
 }
 
-/// Lists MongoDB collections in a database
-pub async fn list_mongodb_collections(
+/// Lists the events of the SQL containers of a restorable database, optionally within a time range
+pub async fn list_restorable_sql_containers(
+    context: &ExecutionContext,
+    instance_id: &str,
+    location: &str,
+    database_rid: &str,
+    start_time: Option<&str>,
+    end_time: Option<&str>,
+    subscription_id: Option<&str>
+) -> Result<Vec<RestorableSqlContainer>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("restorable-container")
+        .subcommand("list")
+        .param("--instance-id", instance_id)
+        .param("--location", location)
+        .param("--database-rid", database_rid);
+    
+    if let Some(start) = start_time {
+        builder = builder.param("--start-time", start);
+    }
+    
+    if let Some(end) = end_time {
+        builder = builder.param("--end-time", end);
+    }
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Lists the SQL databases and containers that can be restored to a point in time
+pub async fn list_restorable_sql_resources(
+    context: &ExecutionContext,
+    instance_id: &str,
+    location: &str,
+    restore_location: &str,
+    restore_timestamp: &str,
+    subscription_id: Option<&str>
+) -> Result<Vec<RestorableSqlResource>> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("sql")
+        .subcommand("restorable-resource")
+        .subcommand("list")
+        .param("--instance-id", instance_id)
+        .param("--location", location)
+        .param("--restore-location", restore_location)
+        .param("--restore-timestamp", restore_timestamp);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Gets the latest restorable backup time of a SQL container in a region
+pub async fn retrieve_latest_sql_backup_time(
+    context: &ExecutionContext,
     account_name: &str,
     resource_group: &str,
     database_name: &str,
+    container_name: &str,
+    location: &str,
     subscription_id: Option<&str>
-) -> Result<Vec<CosmosContainer>> {
-    let mut builder = AzCommandBuilder::new()
+) -> Result<BackupInformation> {
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
-        .subcommand("mongodb")
-        .subcommand("collection")
-        .subcommand("list")
+        .subcommand("sql")
+        .subcommand("retrieve-latest-backup-time")
         .param("--account-name", account_name)
         .param("--resource-group", resource_group)
-        .param("--database-name", database_name);
+        .param("--database-name", database_name)
+        .param("--container-name", container_name)
+        .param("--location", location);
     
     if let Some(sub) = subscription_id {
         builder = builder.subscription(Some(sub));
@@ -254,16 +1097,79 @@ pub async fn list_mongodb_collections(
     builder.execute().await
 }
 
+/// Computes the earliest and latest timestamps a SQL container can be restored to
+///
+/// Combines the live restorable account's earliest restorable time, the container's
+/// creation events and its latest backup time; see [`RestoreWindow::for_container`]. The
+/// container events are read from the database's newest creation or update, so a database
+/// that was deleted and created again under the same name resolves to its live instance.
+///
+/// Returns `None` when the container has no restorable point in the region. An account
+/// without a live restorable instance there (continuous backup disabled) is
+/// [`AzureError::ResourceNotFound`].
+pub async fn get_sql_container_restore_window(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    container_name: &str,
+    location: &str,
+    subscription_id: Option<&str>
+) -> Result<Option<RestoreWindow>> {
+    let accounts = list_restorable_database_accounts(context, Some(account_name), Some(location), subscription_id).await?;
+    let account = accounts
+        .iter()
+        .filter(|account| !account.is_deleted())
+        .max_by_key(|account| account.created())
+        .ok_or_else(|| AzureError::ResourceNotFound {
+            resource_type: Some("Microsoft.DocumentDB/restorableDatabaseAccounts".to_string()),
+            name: Some(account_name.to_string()),
+            details: Box::new(CliErrorDetails {
+                command: "az cosmosdb restorable-database-account list".to_string(),
+                code: None,
+                message: format!(
+                    "Account '{}' has no restorable instance in '{}'; is continuous backup enabled?",
+                    account_name, location
+                ),
+                correlation_id: None,
+                stderr: String::new(),
+            }),
+        })?;
+
+    let databases = list_restorable_sql_databases(context, account.instance_id(), location, subscription_id).await?;
+    let database_rid = databases
+        .iter()
+        .filter(|database| database.database_name() == Some(database_name))
+        .filter(|database| matches!(
+            database.operation_type(),
+            Some(RestorableOperationType::Create | RestorableOperationType::Recreate | RestorableOperationType::Replace)
+        ))
+        .max_by_key(|database| database.event_time())
+        .and_then(RestorableSqlDatabase::database_rid);
+    let events = match database_rid {
+        Some(rid) => list_restorable_sql_containers(context, account.instance_id(), location, rid, None, None, subscription_id).await?,
+        None => Vec::new(),
+    };
+
+    let backup = retrieve_latest_sql_backup_time(context, account_name, resource_group, database_name, container_name, location, subscription_id).await?;
+    let Some(latest) = backup.latest_restorable_time() else {
+        return Ok(None);
+    };
+
+    Ok(RestoreWindow::for_container(account, &events, container_name, latest))
+}
+
 // === THROUGHPUT COMMANDS (READ-ONLY) ===
 
-/// Gets the throughput settings of a database
+/// Gets the throughput settings of a SQL database
 pub async fn get_database_throughput(
+    context: &ExecutionContext,
     account_name: &str,
     resource_group: &str,
     database_name: &str,
     subscription_id: Option<&str>
 ) -> Result<ThroughputSettings> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("sql")
         .subcommand("database")
@@ -280,15 +1186,16 @@ pub async fn get_database_throughput(
     builder.execute().await
 }
 
-/// Gets the throughput settings of a container
+/// Gets the throughput settings of a SQL container
 pub async fn get_container_throughput(
+    context: &ExecutionContext,
     account_name: &str,
     resource_group: &str,
     database_name: &str,
     container_name: &str,
     subscription_id: Option<&str>
 ) -> Result<ThroughputSettings> {
-    let mut builder = AzCommandBuilder::new()
+    let mut builder = AzCommandBuilder::with_context(context)
         .subcommand("cosmosdb")
         .subcommand("sql")
         .subcommand("container")
@@ -304,4 +1211,320 @@ pub async fn get_container_throughput(
     }
     
     builder.execute().await
-} 
\ No newline at end of file
+} 
+
+/// Gets the throughput settings of a MongoDB database
+pub async fn get_mongodb_database_throughput(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    subscription_id: Option<&str>
+) -> Result<ThroughputSettings> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("mongodb")
+        .subcommand("database")
+        .subcommand("throughput")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--name", database_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Gets the throughput settings of a MongoDB collection
+pub async fn get_mongodb_collection_throughput(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    collection_name: &str,
+    subscription_id: Option<&str>
+) -> Result<ThroughputSettings> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("mongodb")
+        .subcommand("collection")
+        .subcommand("throughput")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name)
+        .param("--name", collection_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Gets the throughput settings of a Cassandra keyspace
+pub async fn get_cassandra_keyspace_throughput(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    keyspace_name: &str,
+    subscription_id: Option<&str>
+) -> Result<ThroughputSettings> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("cassandra")
+        .subcommand("keyspace")
+        .subcommand("throughput")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--name", keyspace_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Gets the throughput settings of a Cassandra table
+pub async fn get_cassandra_table_throughput(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    keyspace_name: &str,
+    table_name: &str,
+    subscription_id: Option<&str>
+) -> Result<ThroughputSettings> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("cassandra")
+        .subcommand("table")
+        .subcommand("throughput")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--keyspace-name", keyspace_name)
+        .param("--name", table_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Gets the throughput settings of a Gremlin database
+pub async fn get_gremlin_database_throughput(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    subscription_id: Option<&str>
+) -> Result<ThroughputSettings> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("gremlin")
+        .subcommand("database")
+        .subcommand("throughput")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--name", database_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Gets the throughput settings of a Gremlin graph
+pub async fn get_gremlin_graph_throughput(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    graph_name: &str,
+    subscription_id: Option<&str>
+) -> Result<ThroughputSettings> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("gremlin")
+        .subcommand("graph")
+        .subcommand("throughput")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--database-name", database_name)
+        .param("--name", graph_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Gets the throughput settings of a Table API table
+pub async fn get_table_throughput(
+    context: &ExecutionContext,
+    account_name: &str,
+    resource_group: &str,
+    table_name: &str,
+    subscription_id: Option<&str>
+) -> Result<ThroughputSettings> {
+    let mut builder = AzCommandBuilder::with_context(context)
+        .subcommand("cosmosdb")
+        .subcommand("table")
+        .subcommand("throughput")
+        .subcommand("show")
+        .param("--account-name", account_name)
+        .param("--resource-group", resource_group)
+        .param("--name", table_name);
+    
+    if let Some(sub) = subscription_id {
+        builder = builder.subscription(Some(sub));
+    }
+    
+    builder.execute().await
+}
+
+/// Gets the throughput settings of a database or container of any API, dispatching on `api`
+///
+/// `database_name` names the database (keyspace for Cassandra) and `container_name` the
+/// container, collection, table or graph inside it. Table API tables have no parent
+/// database, so they are named by `database_name` with no `container_name`.
+pub async fn get_api_throughput(
+    context: &ExecutionContext,
+    api: ApiKind,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    container_name: Option<&str>,
+    subscription_id: Option<&str>
+) -> Result<ThroughputSettings> {
+    match (api, container_name) {
+        (ApiKind::Sql, None) => get_database_throughput(context, account_name, resource_group, database_name, subscription_id).await,
+        (ApiKind::Sql, Some(container)) => get_container_throughput(context, account_name, resource_group, database_name, container, subscription_id).await,
+        (ApiKind::MongoDB, None) => get_mongodb_database_throughput(context, account_name, resource_group, database_name, subscription_id).await,
+        (ApiKind::MongoDB, Some(collection)) => get_mongodb_collection_throughput(context, account_name, resource_group, database_name, collection, subscription_id).await,
+        (ApiKind::Cassandra, None) => get_cassandra_keyspace_throughput(context, account_name, resource_group, database_name, subscription_id).await,
+        (ApiKind::Cassandra, Some(table)) => get_cassandra_table_throughput(context, account_name, resource_group, database_name, table, subscription_id).await,
+        (ApiKind::Gremlin, None) => get_gremlin_database_throughput(context, account_name, resource_group, database_name, subscription_id).await,
+        (ApiKind::Gremlin, Some(graph)) => get_gremlin_graph_throughput(context, account_name, resource_group, database_name, graph, subscription_id).await,
+        (ApiKind::Table, None) => get_table_throughput(context, account_name, resource_group, database_name, subscription_id).await,
+        (ApiKind::Table, Some(container)) => Err(table_container_error("az cosmosdb table throughput show", account_name, container)),
+    }
+}
+
+/// Gets the throughput serving a database or container of an account of any API
+///
+/// The API is taken from the account's kind and capabilities. A container without throughput
+/// of its own reports its database's as [`ProvisionedThroughput::SharedAtDatabase`]. A database
+/// or container that does not exist is [`AzureError::ResourceNotFound`]; since Azure reports it
+/// like missing throughput settings, the resource is shown before falling back.
+pub async fn get_provisioned_throughput(
+    context: &ExecutionContext,
+    account: &CosmosAccount,
+    database_name: &str,
+    container_name: Option<&str>
+) -> Result<ProvisionedThroughput> {
+    if account.is_serverless() {
+        return Ok(ProvisionedThroughput::Serverless);
+    }
+
+    let api = account.api_kind();
+    let subscription_id = account.id.subscription_id();
+    let lookup = |container_name| {
+        get_api_throughput(context, api, &account.name, &account.resource_group, database_name, container_name, subscription_id)
+    };
+
+    let error = match lookup(container_name).await {
+        Ok(settings) => return Ok(ProvisionedThroughput::Dedicated(settings)),
+        Err(error) => error,
+    };
+    if is_serverless_error(&error) {
+        return Ok(ProvisionedThroughput::Serverless);
+    }
+    if !is_throughput_not_found(&error) {
+        return Err(error);
+    }
+
+    // Cosmos DB answers a missing database or container the same way, so confirm it exists
+    show_api_resource(context, api, &account.name, &account.resource_group, database_name, container_name, subscription_id).await?;
+    if container_name.is_none() {
+        return Ok(ProvisionedThroughput::NotProvisioned);
+    }
+
+    match lookup(None).await {
+        Ok(settings) => Ok(ProvisionedThroughput::SharedAtDatabase(settings)),
+        Err(error) if is_throughput_not_found(&error) => Ok(ProvisionedThroughput::NotProvisioned),
+        Err(error) => Err(error),
+    }
+}
+
+/// Shows the database or container of any API, to tell whether it exists
+async fn show_api_resource(
+    context: &ExecutionContext,
+    api: ApiKind,
+    account_name: &str,
+    resource_group: &str,
+    database_name: &str,
+    container_name: Option<&str>,
+    subscription_id: Option<&str>
+) -> Result<()> {
+    match (api, container_name) {
+        (ApiKind::Sql, None) => show_sql_database(context, account_name, resource_group, database_name, subscription_id).await.map(drop),
+        (ApiKind::Sql, Some(container)) => show_sql_container(context, account_name, resource_group, database_name, container, subscription_id).await.map(drop),
+        (ApiKind::MongoDB, None) => show_mongodb_database(context, account_name, resource_group, database_name, subscription_id).await.map(drop),
+        (ApiKind::MongoDB, Some(collection)) => show_mongodb_collection(context, account_name, resource_group, database_name, collection, subscription_id).await.map(drop),
+        (ApiKind::Cassandra, None) => show_cassandra_keyspace(context, account_name, resource_group, database_name, subscription_id).await.map(drop),
+        (ApiKind::Cassandra, Some(table)) => show_cassandra_table(context, account_name, resource_group, database_name, table, subscription_id).await.map(drop),
+        (ApiKind::Gremlin, None) => show_gremlin_database(context, account_name, resource_group, database_name, subscription_id).await.map(drop),
+        (ApiKind::Gremlin, Some(graph)) => show_gremlin_graph(context, account_name, resource_group, database_name, graph, subscription_id).await.map(drop),
+        (ApiKind::Table, None) => show_table(context, account_name, resource_group, database_name, subscription_id).await.map(drop),
+        (ApiKind::Table, Some(container)) => Err(table_container_error("az cosmosdb table show", account_name, container)),
+    }
+}
+
+/// Error for a container named inside a Table API account, whose tables have no parent database
+fn table_container_error(command: &str, account_name: &str, container_name: &str) -> AzureError {
+    AzureError::InvalidArgument {
+        argument: Some("--database-name".to_string()),
+        details: Box::new(CliErrorDetails {
+            command: command.to_string(),
+            code: None,
+            message: format!(
+                "Table API account '{}' has no databases; name table '{}' without a parent database",
+                account_name, container_name
+            ),
+            correlation_id: None,
+            stderr: String::new(),
+        }),
+    }
+}
+
+/// Whether a `throughput show` failed with a not-found that may mean the resource has no
+/// throughput settings
+///
+/// The error code must be `NotFound` or `ResourceNotFound`, and an ARM error must name the
+/// `throughputSettings` resource; one naming the account or another parent is final.
+fn is_throughput_not_found(error: &AzureError) -> bool {
+    let AzureError::ResourceNotFound { resource_type, details, .. } = error else {
+        return false;
+    };
+    let names_other_resource = resource_type
+        .as_deref()
+        .is_some_and(|resource_type| resource_type.contains('/') && !resource_type.ends_with("/throughputSettings"));
+    matches!(details.code.as_deref(), Some("NotFound" | "ResourceNotFound")) && !names_other_resource
+}
+
+/// Whether a `throughput show` was rejected because the account is serverless
+fn is_serverless_error(error: &AzureError) -> bool {
+    !matches!(error, AzureError::ResourceNotFound { .. })
+        && error.details().is_some_and(|details| details.message.to_ascii_lowercase().contains("serverless"))
+}
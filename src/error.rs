@@ -1,6 +1,8 @@
 //! Error handling for Azure CLI operations
 
-use std::process::Output;
+use crate::executor::CommandOutput;
+use crate::retry::{is_transient_stderr, retry_after_hint};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type for Azure operations
@@ -21,9 +23,69 @@ pub enum AzureError {
     #[error("Failed to execute Azure CLI command '{command}': {error}")]
     CliExecution { command: String, error: String },
 
-    /// Azure CLI returned an error
-    #[error("Azure CLI error in command '{command}': {stderr}")]
-    CliError { command: String, stderr: String },
+    /// The requested resource does not exist
+    #[error("Resource not found in command '{}': {}", .details.command, .details.message)]
+    ResourceNotFound {
+        /// Resource type (e.g. `Microsoft.DocumentDB/databaseAccounts` or `cosmosdb sql container`)
+        resource_type: Option<String>,
+        /// Resource name
+        name: Option<String>,
+        details: Box<CliErrorDetails>,
+    },
+
+    /// The signed-in principal is not allowed to perform the operation
+    #[error("Authorization failed in command '{}': {}", .details.command, .details.message)]
+    AuthorizationFailed {
+        /// Denied action (e.g. `Microsoft.DocumentDB/databaseAccounts/read`)
+        action: Option<String>,
+        /// Scope the action was denied on
+        scope: Option<String>,
+        details: Box<CliErrorDetails>,
+    },
+
+    /// The subscription does not exist or is not accessible
+    #[error("Subscription not found in command '{}': {}", .details.command, .details.message)]
+    SubscriptionNotFound {
+        /// Subscription name or ID
+        subscription: Option<String>,
+        details: Box<CliErrorDetails>,
+    },
+
+    /// The request was throttled by Azure Resource Manager
+    #[error("Request throttled in command '{}': {}", .details.command, .details.message)]
+    Throttled {
+        /// Delay Azure asked to wait before retrying
+        retry_after: Option<Duration>,
+        details: Box<CliErrorDetails>,
+    },
+
+    /// The command needs an Azure CLI extension that is not installed
+    #[error("Azure CLI extension required by command '{}': {}", .details.command, .details.message)]
+    ExtensionRequired {
+        /// Extension name
+        extension: Option<String>,
+        details: Box<CliErrorDetails>,
+    },
+
+    /// The command was invoked with missing or invalid arguments
+    #[error("Invalid argument in command '{}': {}", .details.command, .details.message)]
+    InvalidArgument {
+        /// Offending argument, if reported
+        argument: Option<String>,
+        details: Box<CliErrorDetails>,
+    },
+
+    /// Azure CLI returned an error that does not fit any other variant
+    #[error("Azure CLI error in command '{}': {}", .details.command, .details.stderr)]
+    CliError { details: Box<CliErrorDetails> },
+
+    /// A string is not a valid Azure resource ID, or not the kind of resource expected
+    #[error("Invalid resource ID '{id}': {reason}")]
+    InvalidResourceId { id: String, reason: String },
+
+    /// Azure CLI command did not finish within the configured timeout
+    #[error("Azure CLI command '{command}' timed out after {elapsed:?}")]
+    Timeout { command: String, elapsed: Duration },
 
     /// Failed to parse JSON response from Azure CLI
     #[error("Failed to parse JSON response: {0}")]
@@ -38,22 +100,276 @@ pub enum AzureError {
     Custom(String),
 }
 
+/// Context extracted from the stderr of a failed Azure CLI command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliErrorDetails {
+    /// Command that failed
+    pub command: String,
+    /// ARM error code (e.g. `ResourceNotFound`)
+    pub code: Option<String>,
+    /// Error message without the `ERROR:` prefix and error code
+    pub message: String,
+    /// Correlation, tracking or activity ID reported by Azure
+    pub correlation_id: Option<String>,
+    /// Complete stderr
+    pub stderr: String,
+}
+
+impl CliErrorDetails {
+    /// Parses the error code, message and correlation ID out of stderr
+    pub fn parse(command: &str, stderr: &str) -> Self {
+        Self {
+            command: command.to_string(),
+            code: parse_error_code(stderr),
+            message: parse_message(stderr),
+            correlation_id: parse_correlation_id(stderr),
+            stderr: stderr.to_string(),
+        }
+    }
+}
+
 impl AzureError {
     /// Creates an AzureError from command output
-    pub fn from_command_output(command: &str, output: Output) -> Self {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        
-        if !stderr.is_empty() {
-            AzureError::CliError {
-                command: command.to_string(),
-                stderr: stderr.to_string(),
-            }
+    pub fn from_command_output(command: &str, output: &CommandOutput) -> Self {
+        if !output.stderr.is_empty() {
+            AzureError::from_stderr(command, &output.stderr)
         } else {
             AzureError::CliExecution {
                 command: command.to_string(),
-                error: format!("Command failed with exit code: {}", 
-                    output.status.code().unwrap_or(-1)),
+                error: format!("Command failed with exit code: {}",
+                    output.exit_code.unwrap_or(-1)),
             }
         }
     }
-} 
\ No newline at end of file
+
+    /// Classifies the stderr of a failed command into a typed error
+    pub fn from_stderr(command: &str, stderr: &str) -> Self {
+        let details = Box::new(CliErrorDetails::parse(command, stderr));
+        let code = details.code.as_deref().unwrap_or_default();
+        let lowercase = stderr.to_lowercase();
+
+        if matches!(code, "AuthenticationFailed" | "InvalidAuthenticationToken" | "ExpiredAuthenticationToken")
+            || lowercase.contains("az login")
+            || lowercase.contains("aadsts")
+        {
+            return AzureError::Authentication;
+        }
+
+        if code == "TooManyRequests"
+            || lowercase.contains("too many requests")
+            || lowercase.contains("(429)")
+            || lowercase.contains("throttl")
+        {
+            return AzureError::Throttled {
+                retry_after: retry_after_hint(stderr),
+                details,
+            };
+        }
+
+        if matches!(code, "AuthorizationFailed" | "LinkedAuthorizationFailed")
+            || lowercase.contains("does not have authorization to perform action")
+        {
+            return AzureError::AuthorizationFailed {
+                action: quoted_after(stderr, "perform action"),
+                scope: quoted_after(stderr, "over scope"),
+                details,
+            };
+        }
+
+        if matches!(code, "SubscriptionNotFound" | "InvalidSubscriptionId")
+            || ((lowercase.contains("subscription '") || lowercase.contains("subscription of '"))
+                && (lowercase.contains("not found") || lowercase.contains("doesn't exist")))
+        {
+            return AzureError::SubscriptionNotFound {
+                subscription: quoted_after(stderr, "subscription")
+                    .or_else(|| quoted_after(stderr, "subscription of"))
+                    .or_else(|| argument_value(command, &["--subscription"])),
+                details,
+            };
+        }
+
+        if matches!(code, "ResourceNotFound" | "ResourceGroupNotFound" | "ParentResourceNotFound" | "NotFound")
+            || lowercase.contains("was not found")
+            || lowercase.contains("could not be found")
+        {
+            let (resource_type, name) = not_found_resource(stderr)
+                .map(|(resource_type, name)| (Some(resource_type), Some(name)))
+                .unwrap_or_else(|| (command_resource_type(command), argument_value(command, &["--name", "-n"])));
+            return AzureError::ResourceNotFound {
+                resource_type,
+                name,
+                details,
+            };
+        }
+
+        if lowercase.contains("requires the extension") || lowercase.contains("use_dynamic_install") {
+            return AzureError::ExtensionRequired {
+                extension: word_after(stderr, "requires the extension"),
+                details,
+            };
+        }
+
+        if lowercase.contains("unrecognized arguments")
+            || lowercase.contains("the following arguments are required")
+            || lowercase.contains("expected one argument")
+            || lowercase.contains("invalid choice")
+            || lowercase.contains("is misspelled or not recognized")
+            || (code.starts_with("Invalid") && code != "InvalidResourceType")
+        {
+            return AzureError::InvalidArgument {
+                argument: invalid_argument(stderr),
+                details,
+            };
+        }
+
+        AzureError::CliError { details }
+    }
+
+    /// Gets the details of a failed Azure CLI command, if this error came from one
+    pub fn details(&self) -> Option<&CliErrorDetails> {
+        match self {
+            AzureError::ResourceNotFound { details, .. }
+            | AzureError::AuthorizationFailed { details, .. }
+            | AzureError::SubscriptionNotFound { details, .. }
+            | AzureError::Throttled { details, .. }
+            | AzureError::ExtensionRequired { details, .. }
+            | AzureError::InvalidArgument { details, .. }
+            | AzureError::CliError { details } => Some(details),
+            _ => None,
+        }
+    }
+
+    /// Gets the ARM error code (e.g. `ResourceNotFound`), if reported
+    pub fn error_code(&self) -> Option<&str> {
+        self.details()?.code.as_deref()
+    }
+
+    /// Gets the correlation or tracking ID reported by Azure, if any
+    pub fn correlation_id(&self) -> Option<&str> {
+        self.details()?.correlation_id.as_deref()
+    }
+
+    /// Whether the error is transient (throttling, 5xx, dropped connection) and worth retrying
+    pub fn is_transient(&self) -> bool {
+        match self {
+            AzureError::Throttled { .. } => true,
+            AzureError::CliError { details } => is_transient_stderr(&details.stderr),
+            _ => false,
+        }
+    }
+
+    /// Gets the `Retry-After` hint reported by Azure, if any
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            AzureError::Throttled { retry_after, .. } => *retry_after,
+            _ => retry_after_hint(&self.details()?.stderr),
+        }
+    }
+}
+
+/// Extracts the ARM error code from a `Code: X` line or an `ERROR: (X) ...` prefix
+fn parse_error_code(stderr: &str) -> Option<String> {
+    let from_line = stderr
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Code:"))
+        .map(str::trim)
+        .filter(|code| !code.is_empty());
+
+    let from_prefix = || {
+        let first_line = stderr.lines().next()?.trim();
+        let rest = first_line.strip_prefix("ERROR:").unwrap_or(first_line).trim_start();
+        let (code, _) = rest.strip_prefix('(')?.split_once(')')?;
+        code.chars().all(|c| c.is_ascii_alphanumeric()).then_some(code)
+    };
+
+    from_line.or_else(from_prefix).map(str::to_string)
+}
+
+/// Extracts the human readable message from stderr
+fn parse_message(stderr: &str) -> String {
+    let first_line = stderr.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or_default();
+    let message = first_line.strip_prefix("ERROR:").unwrap_or(first_line).trim_start();
+
+    match message.strip_prefix('(').and_then(|rest| rest.split_once(')')) {
+        Some((code, rest)) if code.chars().all(|c| c.is_ascii_alphanumeric()) => rest.trim().to_string(),
+        _ => message.to_string(),
+    }
+}
+
+/// Extracts a correlation, tracking or activity ID from stderr
+fn parse_correlation_id(stderr: &str) -> Option<String> {
+    const MARKERS: &[&str] = &["correlation id", "correlationid", "tracking id", "trackingid", "activityid", "activity id"];
+    let lowercase = stderr.to_ascii_lowercase();
+
+    MARKERS.iter().find_map(|marker| {
+        let start = lowercase.find(marker)? + marker.len();
+        let id: String = stderr[start..]
+            .trim_start_matches(|c: char| matches!(c, ':' | '=' | '\'' | '"' | '\\') || c.is_whitespace())
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+            .collect();
+        (id.len() >= 8).then_some(id)
+    })
+}
+
+/// Returns the quoted value following `marker`, e.g. `action 'x'` -> `x`
+fn quoted_after(text: &str, marker: &str) -> Option<String> {
+    let start = text.to_ascii_lowercase().find(&marker.to_ascii_lowercase())? + marker.len();
+    let rest = text[start..].trim_start();
+    let quote = rest.chars().next().filter(|c| matches!(c, '\'' | '"'))?;
+    let (value, _) = rest[1..].split_once(quote)?;
+    Some(value.to_string())
+}
+
+/// Returns the word following `marker`, without trailing punctuation
+fn word_after(text: &str, marker: &str) -> Option<String> {
+    let start = text.to_ascii_lowercase().find(marker)? + marker.len();
+    let word = text[start..]
+        .split_whitespace()
+        .next()?
+        .trim_matches(|c: char| matches!(c, '\'' | '"' | '.' | ',' | ':'));
+    (!word.is_empty()).then(|| word.to_string())
+}
+
+/// Extracts the offending argument from an argparse-style usage error
+fn invalid_argument(stderr: &str) -> Option<String> {
+    ["unrecognized arguments:", "the following arguments are required:", "argument"]
+        .iter()
+        .find_map(|marker| word_after(stderr, marker))
+        .filter(|argument| argument.starts_with('-'))
+}
+
+/// Extracts the type and name of a missing resource from an ARM "not found" message
+fn not_found_resource(stderr: &str) -> Option<(String, String)> {
+    if let Some(path) = quoted_after(stderr, "The Resource")
+        && let Some((resource_type, name)) = path.rsplit_once('/')
+    {
+        return Some((resource_type.to_string(), name.to_string()));
+    }
+
+    quoted_after(stderr, "Resource group")
+        .map(|name| ("Microsoft.Resources/resourceGroups".to_string(), name))
+}
+
+/// Derives a resource type from the command groups of a command line
+/// (`az cosmosdb sql container show ...` -> `cosmosdb sql container`)
+fn command_resource_type(command: &str) -> Option<String> {
+    let mut groups: Vec<&str> = command
+        .split_whitespace()
+        .skip_while(|token| *token == "az")
+        .take_while(|token| !token.starts_with('-'))
+        .collect();
+
+    while matches!(groups.last(), Some(&("show" | "list" | "throughput"))) {
+        groups.pop();
+    }
+
+    (!groups.is_empty()).then(|| groups.join(" "))
+}
+
+/// Returns the value passed to one of `flags` in a command line
+fn argument_value(command: &str, flags: &[&str]) -> Option<String> {
+    let mut tokens = command.split_whitespace();
+    tokens.find(|token| flags.contains(token))?;
+    tokens.next().map(str::to_string)
+}
@@ -0,0 +1,248 @@
+//! Pluggable backends for running Azure CLI commands
+//!
+//! `AzCommandBuilder` never spawns `az` itself; it hands the argument list to a
+//! [`CommandExecutor`]. [`ProcessExecutor`] runs the real Azure CLI and is the default,
+//! while [`ScriptedExecutor`] serves canned responses so code built on [`AzureClient`]
+//! can be tested without a logged-in Azure CLI.
+//!
+//! [`AzureClient`]: crate::AzureClient
+
+use crate::error::{AzureError, Result};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::process::Command;
+
+/// Future returned by [`CommandExecutor::execute`]
+pub type ExecutorFuture<'a> = Pin<Box<dyn Future<Output = Result<CommandOutput>> + Send + 'a>>;
+
+/// Raw result of an Azure CLI invocation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandOutput {
+    /// Process exit code (`None` if the process was terminated by a signal)
+    pub exit_code: Option<i32>,
+    /// Captured standard output
+    pub stdout: String,
+    /// Captured standard error
+    pub stderr: String,
+}
+
+impl CommandOutput {
+    /// Creates a successful output with the given stdout
+    pub fn success(stdout: impl Into<String>) -> Self {
+        Self {
+            exit_code: Some(0),
+            stdout: stdout.into(),
+            stderr: String::new(),
+        }
+    }
+
+    /// Creates a failed output with the given exit code and stderr
+    pub fn failure(exit_code: i32, stderr: impl Into<String>) -> Self {
+        Self {
+            exit_code: Some(exit_code),
+            stdout: String::new(),
+            stderr: stderr.into(),
+        }
+    }
+
+    /// Whether the command exited successfully
+    pub fn is_success(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+/// Backend that runs Azure CLI commands
+///
+/// `args` never contains the leading `az` nor the `--output json` flag; executors that
+/// spawn the real CLI are expected to add them.
+pub trait CommandExecutor: Send + Sync {
+    /// Executes `az` with the given arguments
+    fn execute<'a>(&'a self, args: &'a [String]) -> ExecutorFuture<'a>;
+}
+
+impl<E: CommandExecutor + ?Sized> CommandExecutor for Arc<E> {
+    fn execute<'a>(&'a self, args: &'a [String]) -> ExecutorFuture<'a> {
+        (**self).execute(args)
+    }
+}
+
+/// Executor that spawns the installed Azure CLI
+///
+/// Processes are driven by `tokio::process`, so awaiting a command never blocks a
+/// runtime worker thread and concurrent invocations run in parallel. The child process
+/// is killed if the returned future is dropped before it completes, which is how
+/// timeouts and cancellation are enforced.
+#[derive(Debug, Clone)]
+pub struct ProcessExecutor {
+    program: PathBuf,
+}
+
+impl ProcessExecutor {
+    /// Creates an executor that runs `az` from the `PATH`
+    pub fn new() -> Self {
+        Self::with_program("az")
+    }
+
+    /// Creates an executor that runs the Azure CLI at the given path
+    ///
+    /// Use it when `az` is not on the `PATH`, or to run a wrapper script.
+    pub fn with_program(program: impl Into<PathBuf>) -> Self {
+        Self {
+            program: program.into(),
+        }
+    }
+
+    /// Gets the program that is spawned for each command
+    pub fn program(&self) -> &Path {
+        &self.program
+    }
+}
+
+impl Default for ProcessExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandExecutor for ProcessExecutor {
+    fn execute<'a>(&'a self, args: &'a [String]) -> ExecutorFuture<'a> {
+        Box::pin(async move {
+            let mut command = Command::new(&self.program);
+            command
+                .args(args)
+                .arg("--output")
+                .arg("json")
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .kill_on_drop(true);
+
+            let output = command.output().await?;
+
+            Ok(CommandOutput {
+                exit_code: output.status.code(),
+                stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+                stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            })
+        })
+    }
+}
+
+/// In-memory executor that serves pre-registered responses
+///
+/// Each response is matched against the exact argument list and served once, in the
+/// order it was registered. Invocations without a matching response fail with
+/// [`AzureError::Custom`].
+///
+/// ```
+/// use rust_az_wrapper::executor::{CommandOutput, ScriptedExecutor};
+/// use rust_az_wrapper::AzureClient;
+///
+/// # tokio_test::block_on(async {
+/// let executor = ScriptedExecutor::new()
+///     .respond(&["cosmosdb", "list"], CommandOutput::success("[]"));
+/// let client = AzureClient::with_executor(executor).unwrap();
+///
+/// let accounts = client.list_cosmos_accounts(None).await.unwrap();
+/// assert!(accounts.is_empty());
+/// # });
+/// ```
+#[derive(Debug, Default)]
+pub struct ScriptedExecutor {
+    responses: Mutex<Vec<ScriptedResponse>>,
+    calls: Mutex<Vec<Vec<String>>>,
+}
+
+/// A registered response of a [`ScriptedExecutor`]
+#[derive(Debug)]
+struct ScriptedResponse {
+    args: Vec<String>,
+    output: CommandOutput,
+    delay: Option<Duration>,
+}
+
+impl ScriptedExecutor {
+    /// Creates an executor with no scripted responses
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a response for the given arguments
+    pub fn respond(self, args: &[&str], output: CommandOutput) -> Self {
+        self.push_response(args, output);
+        self
+    }
+
+    /// Registers a response that is only served after the given delay
+    ///
+    /// Use it to simulate slow commands, e.g. to exercise timeouts.
+    pub fn respond_after(self, args: &[&str], delay: Duration, output: CommandOutput) -> Self {
+        self.push(args, output, Some(delay));
+        self
+    }
+
+    /// Registers a response for the given arguments on a shared executor
+    pub fn push_response(&self, args: &[&str], output: CommandOutput) {
+        self.push(args, output, None);
+    }
+
+    fn push(&self, args: &[&str], output: CommandOutput, delay: Option<Duration>) {
+        self.responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(ScriptedResponse {
+                args: args.iter().map(|s| s.to_string()).collect(),
+                output,
+                delay,
+            });
+    }
+
+    /// Returns the argument lists of every invocation so far
+    pub fn calls(&self) -> Vec<Vec<String>> {
+        self.calls
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns the number of responses not yet served
+    pub fn remaining(&self) -> usize {
+        self.responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+}
+
+impl CommandExecutor for ScriptedExecutor {
+    fn execute<'a>(&'a self, args: &'a [String]) -> ExecutorFuture<'a> {
+        Box::pin(async move {
+            self.calls
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(args.to_vec());
+
+            let response = {
+                let mut responses = self.responses.lock().unwrap_or_else(PoisonError::into_inner);
+                responses
+                    .iter()
+                    .position(|response| response.args.as_slice() == args)
+                    .map(|index| responses.remove(index))
+            };
+
+            let response = response.ok_or_else(|| {
+                AzureError::Custom(format!("No scripted response for 'az {}'", args.join(" ")))
+            })?;
+
+            if let Some(delay) = response.delay {
+                tokio::time::sleep(delay).await;
+            }
+
+            Ok(response.output)
+        })
+    }
+}
@@ -0,0 +1,766 @@
+//! Typed handles for navigating Cosmos DB resources
+//!
+//! Handles name a resource without fetching it, so nested resources are reached by
+//! navigation instead of long lists of positional names:
+//!
+//! ```no_run
+//! use rust_az_wrapper::AzureClient;
+//!
+//! # async fn example() -> rust_az_wrapper::Result<()> {
+//! let client = AzureClient::new()?;
+//! let container = client
+//!     .cosmos_account("cosmos-rg", "contoso-cosmos")
+//!     .sql_database("appdb")
+//!     .container("orders")
+//!     .show()
+//!     .await?;
+//!
+//! // Handles can also be obtained from returned models
+//! for account in client.list_cosmos_accounts(None).await? {
+//!     let databases = account.handle(&client).sql_databases().await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::AzureClient;
+use crate::commands::cosmos;
+use crate::models::*;
+use crate::resource_id::{AzureResourceId, COSMOS_DB_PROVIDER};
+use crate::Result;
+
+/// Handle to a Cosmos DB account
+#[derive(Debug, Clone)]
+pub struct AccountRef<'a> {
+    client: &'a AzureClient,
+    resource_group: String,
+    name: String,
+    subscription_id: Option<String>,
+}
+
+impl<'a> AccountRef<'a> {
+    /// Creates a handle to an account in the client's subscription
+    pub fn new(client: &'a AzureClient, resource_group: &str, name: &str) -> Self {
+        Self {
+            client,
+            resource_group: resource_group.to_string(),
+            name: name.to_string(),
+            subscription_id: None,
+        }
+    }
+
+    /// Creates a handle from an account resource ID
+    pub fn from_id(client: &'a AzureClient, id: &AzureResourceId) -> Result<Self> {
+        id.expect_type(&format!("{}/databaseAccounts", COSMOS_DB_PROVIDER))?;
+        Ok(Self::new(client, id.require_segment("resourceGroups")?, id.name())
+            .in_subscription(id.subscription_id()))
+    }
+
+    /// Targets a specific subscription instead of the client's subscription
+    pub fn in_subscription(mut self, subscription_id: Option<&str>) -> Self {
+        self.subscription_id = subscription_id.map(str::to_string);
+        self
+    }
+
+    /// Gets the account name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the resource group
+    pub fn resource_group(&self) -> &str {
+        &self.resource_group
+    }
+
+    /// Gets the subscription commands run against (`None` for the Azure CLI default)
+    pub fn subscription_id(&self) -> Option<&str> {
+        self.subscription_id.as_deref().or(self.client.subscription_id())
+    }
+
+    /// Gets the account resource ID, when the subscription is known
+    pub fn id(&self) -> Option<AzureResourceId> {
+        let subscription_id = self.subscription_id()?;
+        Some(AzureResourceId::cosmos_account(subscription_id, &self.resource_group, &self.name))
+    }
+
+    /// Shows the account
+    pub async fn show(&self) -> Result<CosmosAccount> {
+        cosmos::show_account(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Lists the master keys
+    pub async fn keys(&self) -> Result<CosmosKeys> {
+        cosmos::list_keys(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Lists the read-only keys
+    pub async fn read_only_keys(&self) -> Result<CosmosKeys> {
+        cosmos::list_read_only_keys(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Lists the connection strings
+    pub async fn connection_strings(&self) -> Result<CosmosConnectionStrings> {
+        cosmos::list_connection_strings(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Gets the throughput serving a database or container, whatever API the account serves
+    ///
+    /// Shows the account first to find its API; see [`AzureClient::get_throughput`].
+    pub async fn throughput(&self, database_name: &str, container_name: Option<&str>) -> Result<ProvisionedThroughput> {
+        let account = self.show().await?;
+        cosmos::get_provisioned_throughput(self.client.context(), &account, database_name, container_name).await
+    }
+
+    /// Lists the SQL data-plane role definitions, built-in and custom
+    pub async fn sql_role_definitions(&self) -> Result<Vec<SqlRoleDefinition>> {
+        cosmos::list_sql_role_definitions(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Shows a SQL data-plane role definition by its ID
+    pub async fn sql_role_definition(&self, id: &str) -> Result<SqlRoleDefinition> {
+        cosmos::show_sql_role_definition(self.client.context(), &self.name, &self.resource_group, id, self.subscription_id()).await
+    }
+
+    /// Lists the SQL data-plane role assignments
+    pub async fn sql_role_assignments(&self) -> Result<Vec<SqlRoleAssignment>> {
+        cosmos::list_sql_role_assignments(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Shows a SQL data-plane role assignment by its ID
+    pub async fn sql_role_assignment(&self, id: &str) -> Result<SqlRoleAssignment> {
+        cosmos::show_sql_role_assignment(self.client.context(), &self.name, &self.resource_group, id, self.subscription_id()).await
+    }
+
+    /// Lists the MongoDB role definitions
+    pub async fn mongodb_role_definitions(&self) -> Result<Vec<MongoRoleDefinition>> {
+        cosmos::list_mongodb_role_definitions(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Shows a MongoDB role definition by its ID
+    pub async fn mongodb_role_definition(&self, id: &str) -> Result<MongoRoleDefinition> {
+        cosmos::show_mongodb_role_definition(self.client.context(), &self.name, &self.resource_group, id, self.subscription_id()).await
+    }
+
+    /// Lists the MongoDB user definitions
+    pub async fn mongodb_user_definitions(&self) -> Result<Vec<MongoUserDefinition>> {
+        cosmos::list_mongodb_user_definitions(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Shows a MongoDB user definition by its ID
+    pub async fn mongodb_user_definition(&self, id: &str) -> Result<MongoUserDefinition> {
+        cosmos::show_mongodb_user_definition(self.client.context(), &self.name, &self.resource_group, id, self.subscription_id()).await
+    }
+
+    /// Resolves which principals can read or write items in a SQL database or container
+    pub async fn sql_data_access(&self, database_name: &str, container_name: Option<&str>) -> Result<Vec<DataPlaneAccess>> {
+        cosmos::resolve_sql_data_access(self.client.context(), &self.name, &self.resource_group, database_name, container_name, self.subscription_id()).await
+    }
+
+    /// Lists SQL databases
+    pub async fn sql_databases(&self) -> Result<Vec<CosmosDatabase>> {
+        cosmos::list_sql_databases(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Gets a handle to a SQL database
+    pub fn sql_database(&self, name: &str) -> DatabaseRef<'a> {
+        DatabaseRef {
+            account: self.clone(),
+            name: name.to_string(),
+        }
+    }
+
+    /// Lists MongoDB databases
+    pub async fn mongodb_databases(&self) -> Result<Vec<CosmosDatabase>> {
+        cosmos::list_mongodb_databases(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Gets a handle to a MongoDB database
+    pub fn mongodb_database(&self, name: &str) -> MongoDatabaseRef<'a> {
+        MongoDatabaseRef {
+            account: self.clone(),
+            name: name.to_string(),
+        }
+    }
+
+    /// Lists Cassandra keyspaces
+    pub async fn cassandra_keyspaces(&self) -> Result<Vec<CosmosDatabase>> {
+        cosmos::list_cassandra_keyspaces(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Gets a handle to a Cassandra keyspace
+    pub fn cassandra_keyspace(&self, name: &str) -> KeyspaceRef<'a> {
+        KeyspaceRef {
+            account: self.clone(),
+            name: name.to_string(),
+        }
+    }
+
+    /// Lists Gremlin databases
+    pub async fn gremlin_databases(&self) -> Result<Vec<CosmosDatabase>> {
+        cosmos::list_gremlin_databases(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Gets a handle to a Gremlin database
+    pub fn gremlin_database(&self, name: &str) -> GremlinDatabaseRef<'a> {
+        GremlinDatabaseRef {
+            account: self.clone(),
+            name: name.to_string(),
+        }
+    }
+
+    /// Lists Table API tables
+    pub async fn tables(&self) -> Result<Vec<CosmosTable>> {
+        cosmos::list_tables(self.client.context(), &self.name, &self.resource_group, self.subscription_id()).await
+    }
+
+    /// Gets a handle to a Table API table
+    pub fn table(&self, name: &str) -> TableRef<'a> {
+        TableRef {
+            account: self.clone(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Handle to a SQL database
+#[derive(Debug, Clone)]
+pub struct DatabaseRef<'a> {
+    account: AccountRef<'a>,
+    name: String,
+}
+
+impl<'a> DatabaseRef<'a> {
+    /// Creates a handle from a SQL database resource ID
+    pub fn from_id(client: &'a AzureClient, id: &AzureResourceId) -> Result<Self> {
+        id.expect_type(&format!("{}/databaseAccounts/sqlDatabases", COSMOS_DB_PROVIDER))?;
+        let account = AccountRef::new(client, id.require_segment("resourceGroups")?, id.require_segment("databaseAccounts")?);
+        Ok(account.in_subscription(id.subscription_id()).sql_database(id.name()))
+    }
+
+    /// Gets the database name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the account the database belongs to
+    pub fn account(&self) -> &AccountRef<'a> {
+        &self.account
+    }
+
+    /// Gets the database resource ID, when the subscription is known
+    pub fn id(&self) -> Option<AzureResourceId> {
+        Some(self.account.id()?.child("sqlDatabases", &self.name))
+    }
+
+    /// Shows the database
+    pub async fn show(&self) -> Result<CosmosDatabase> {
+        let account = &self.account;
+        cosmos::show_sql_database(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets the database throughput settings
+    pub async fn throughput(&self) -> Result<ThroughputSettings> {
+        let account = &self.account;
+        cosmos::get_database_throughput(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Lists containers
+    pub async fn containers(&self) -> Result<Vec<CosmosContainer>> {
+        let account = &self.account;
+        cosmos::list_sql_containers(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets a handle to a container
+    pub fn container(&self, name: &str) -> ContainerRef<'a> {
+        ContainerRef {
+            database: self.clone(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Handle to a SQL container
+#[derive(Debug, Clone)]
+pub struct ContainerRef<'a> {
+    database: DatabaseRef<'a>,
+    name: String,
+}
+
+impl<'a> ContainerRef<'a> {
+    /// Creates a handle from a SQL container resource ID
+    pub fn from_id(client: &'a AzureClient, id: &AzureResourceId) -> Result<Self> {
+        id.expect_type(&format!("{}/databaseAccounts/sqlDatabases/containers", COSMOS_DB_PROVIDER))?;
+        let account = AccountRef::new(client, id.require_segment("resourceGroups")?, id.require_segment("databaseAccounts")?);
+        let database = account.in_subscription(id.subscription_id()).sql_database(id.require_segment("sqlDatabases")?);
+        Ok(database.container(id.name()))
+    }
+
+    /// Gets the container name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the database the container belongs to
+    pub fn database(&self) -> &DatabaseRef<'a> {
+        &self.database
+    }
+
+    /// Gets the container resource ID, when the subscription is known
+    pub fn id(&self) -> Option<AzureResourceId> {
+        Some(self.database.id()?.child("containers", &self.name))
+    }
+
+    /// Shows the container
+    pub async fn show(&self) -> Result<CosmosContainer> {
+        let account = &self.database.account;
+        cosmos::show_sql_container(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets the container throughput settings
+    pub async fn throughput(&self) -> Result<ThroughputSettings> {
+        let account = &self.database.account;
+        cosmos::get_container_throughput(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets the latest restorable backup time in a region
+    pub async fn latest_backup_time(&self, location: &str) -> Result<BackupInformation> {
+        let account = &self.database.account;
+        cosmos::retrieve_latest_sql_backup_time(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, location, account.subscription_id()).await
+    }
+
+    /// Computes the earliest and latest timestamps the container can be restored to in a region, or `None` if it has none
+    pub async fn restore_window(&self, location: &str) -> Result<Option<RestoreWindow>> {
+        let account = &self.database.account;
+        cosmos::get_sql_container_restore_window(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, location, account.subscription_id()).await
+    }
+
+    /// Lists the stored procedures
+    pub async fn stored_procedures(&self) -> Result<Vec<StoredProcedure>> {
+        let account = &self.database.account;
+        cosmos::list_sql_stored_procedures(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, account.subscription_id()).await
+    }
+
+    /// Shows a stored procedure, including its body
+    pub async fn stored_procedure(&self, name: &str) -> Result<StoredProcedure> {
+        let account = &self.database.account;
+        cosmos::show_sql_stored_procedure(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, name, account.subscription_id()).await
+    }
+
+    /// Lists the triggers
+    pub async fn triggers(&self) -> Result<Vec<Trigger>> {
+        let account = &self.database.account;
+        cosmos::list_sql_triggers(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, account.subscription_id()).await
+    }
+
+    /// Shows a trigger, including its body
+    pub async fn trigger(&self, name: &str) -> Result<Trigger> {
+        let account = &self.database.account;
+        cosmos::show_sql_trigger(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, name, account.subscription_id()).await
+    }
+
+    /// Lists the user-defined functions
+    pub async fn user_defined_functions(&self) -> Result<Vec<UserDefinedFunction>> {
+        let account = &self.database.account;
+        cosmos::list_sql_user_defined_functions(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, account.subscription_id()).await
+    }
+
+    /// Shows a user-defined function, including its body
+    pub async fn user_defined_function(&self, name: &str) -> Result<UserDefinedFunction> {
+        let account = &self.database.account;
+        cosmos::show_sql_user_defined_function(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, name, account.subscription_id()).await
+    }
+}
+
+/// Handle to a MongoDB database
+#[derive(Debug, Clone)]
+pub struct MongoDatabaseRef<'a> {
+    account: AccountRef<'a>,
+    name: String,
+}
+
+impl<'a> MongoDatabaseRef<'a> {
+    /// Creates a handle from a MongoDB database resource ID
+    pub fn from_id(client: &'a AzureClient, id: &AzureResourceId) -> Result<Self> {
+        id.expect_type(&format!("{}/databaseAccounts/mongodbDatabases", COSMOS_DB_PROVIDER))?;
+        let account = AccountRef::new(client, id.require_segment("resourceGroups")?, id.require_segment("databaseAccounts")?);
+        Ok(account.in_subscription(id.subscription_id()).mongodb_database(id.name()))
+    }
+
+    /// Gets the database name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the account the database belongs to
+    pub fn account(&self) -> &AccountRef<'a> {
+        &self.account
+    }
+
+    /// Gets the database resource ID, when the subscription is known
+    pub fn id(&self) -> Option<AzureResourceId> {
+        Some(self.account.id()?.child("mongodbDatabases", &self.name))
+    }
+
+    /// Shows the database
+    pub async fn show(&self) -> Result<CosmosDatabase> {
+        let account = &self.account;
+        cosmos::show_mongodb_database(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets the database throughput settings
+    pub async fn throughput(&self) -> Result<ThroughputSettings> {
+        let account = &self.account;
+        cosmos::get_mongodb_database_throughput(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Lists collections
+    pub async fn collections(&self) -> Result<Vec<MongoCollection>> {
+        let account = &self.account;
+        cosmos::list_mongodb_collections(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets a handle to a collection
+    pub fn collection(&self, name: &str) -> MongoCollectionRef<'a> {
+        MongoCollectionRef {
+            database: self.clone(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Handle to a MongoDB collection
+#[derive(Debug, Clone)]
+pub struct MongoCollectionRef<'a> {
+    database: MongoDatabaseRef<'a>,
+    name: String,
+}
+
+impl<'a> MongoCollectionRef<'a> {
+    /// Creates a handle from a MongoDB collection resource ID
+    pub fn from_id(client: &'a AzureClient, id: &AzureResourceId) -> Result<Self> {
+        id.expect_type(&format!("{}/databaseAccounts/mongodbDatabases/collections", COSMOS_DB_PROVIDER))?;
+        let account = AccountRef::new(client, id.require_segment("resourceGroups")?, id.require_segment("databaseAccounts")?);
+        let database = account.in_subscription(id.subscription_id()).mongodb_database(id.require_segment("mongodbDatabases")?);
+        Ok(database.collection(id.name()))
+    }
+
+    /// Gets the collection name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the database the collection belongs to
+    pub fn database(&self) -> &MongoDatabaseRef<'a> {
+        &self.database
+    }
+
+    /// Gets the collection resource ID, when the subscription is known
+    pub fn id(&self) -> Option<AzureResourceId> {
+        Some(self.database.id()?.child("collections", &self.name))
+    }
+
+    /// Shows the collection
+    pub async fn show(&self) -> Result<MongoCollection> {
+        let account = &self.database.account;
+        cosmos::show_mongodb_collection(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets the collection throughput settings
+    pub async fn throughput(&self) -> Result<ThroughputSettings> {
+        let account = &self.database.account;
+        cosmos::get_mongodb_collection_throughput(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, account.subscription_id()).await
+    }
+}
+
+/// Handle to a Cassandra keyspace
+#[derive(Debug, Clone)]
+pub struct KeyspaceRef<'a> {
+    account: AccountRef<'a>,
+    name: String,
+}
+
+impl<'a> KeyspaceRef<'a> {
+    /// Creates a handle from a Cassandra keyspace resource ID
+    pub fn from_id(client: &'a AzureClient, id: &AzureResourceId) -> Result<Self> {
+        id.expect_type(&format!("{}/databaseAccounts/cassandraKeyspaces", COSMOS_DB_PROVIDER))?;
+        let account = AccountRef::new(client, id.require_segment("resourceGroups")?, id.require_segment("databaseAccounts")?);
+        Ok(account.in_subscription(id.subscription_id()).cassandra_keyspace(id.name()))
+    }
+
+    /// Gets the keyspace name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the account the keyspace belongs to
+    pub fn account(&self) -> &AccountRef<'a> {
+        &self.account
+    }
+
+    /// Gets the keyspace resource ID, when the subscription is known
+    pub fn id(&self) -> Option<AzureResourceId> {
+        Some(self.account.id()?.child("cassandraKeyspaces", &self.name))
+    }
+
+    /// Shows the keyspace
+    pub async fn show(&self) -> Result<CosmosDatabase> {
+        let account = &self.account;
+        cosmos::show_cassandra_keyspace(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets the keyspace throughput settings
+    pub async fn throughput(&self) -> Result<ThroughputSettings> {
+        let account = &self.account;
+        cosmos::get_cassandra_keyspace_throughput(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Lists tables
+    pub async fn tables(&self) -> Result<Vec<CassandraTable>> {
+        let account = &self.account;
+        cosmos::list_cassandra_tables(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets a handle to a table
+    pub fn table(&self, name: &str) -> CassandraTableRef<'a> {
+        CassandraTableRef {
+            keyspace: self.clone(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Handle to a Cassandra table
+#[derive(Debug, Clone)]
+pub struct CassandraTableRef<'a> {
+    keyspace: KeyspaceRef<'a>,
+    name: String,
+}
+
+impl<'a> CassandraTableRef<'a> {
+    /// Creates a handle from a Cassandra table resource ID
+    pub fn from_id(client: &'a AzureClient, id: &AzureResourceId) -> Result<Self> {
+        id.expect_type(&format!("{}/databaseAccounts/cassandraKeyspaces/tables", COSMOS_DB_PROVIDER))?;
+        let account = AccountRef::new(client, id.require_segment("resourceGroups")?, id.require_segment("databaseAccounts")?);
+        let keyspace = account.in_subscription(id.subscription_id()).cassandra_keyspace(id.require_segment("cassandraKeyspaces")?);
+        Ok(keyspace.table(id.name()))
+    }
+
+    /// Gets the table name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the keyspace the table belongs to
+    pub fn keyspace(&self) -> &KeyspaceRef<'a> {
+        &self.keyspace
+    }
+
+    /// Gets the table resource ID, when the subscription is known
+    pub fn id(&self) -> Option<AzureResourceId> {
+        Some(self.keyspace.id()?.child("tables", &self.name))
+    }
+
+    /// Shows the table
+    pub async fn show(&self) -> Result<CassandraTable> {
+        let account = &self.keyspace.account;
+        cosmos::show_cassandra_table(account.client.context(), &account.name, &account.resource_group, &self.keyspace.name, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets the table throughput settings
+    pub async fn throughput(&self) -> Result<ThroughputSettings> {
+        let account = &self.keyspace.account;
+        cosmos::get_cassandra_table_throughput(account.client.context(), &account.name, &account.resource_group, &self.keyspace.name, &self.name, account.subscription_id()).await
+    }
+}
+
+/// Handle to a Gremlin database
+#[derive(Debug, Clone)]
+pub struct GremlinDatabaseRef<'a> {
+    account: AccountRef<'a>,
+    name: String,
+}
+
+impl<'a> GremlinDatabaseRef<'a> {
+    /// Creates a handle from a Gremlin database resource ID
+    pub fn from_id(client: &'a AzureClient, id: &AzureResourceId) -> Result<Self> {
+        id.expect_type(&format!("{}/databaseAccounts/gremlinDatabases", COSMOS_DB_PROVIDER))?;
+        let account = AccountRef::new(client, id.require_segment("resourceGroups")?, id.require_segment("databaseAccounts")?);
+        Ok(account.in_subscription(id.subscription_id()).gremlin_database(id.name()))
+    }
+
+    /// Gets the database name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the account the database belongs to
+    pub fn account(&self) -> &AccountRef<'a> {
+        &self.account
+    }
+
+    /// Gets the database resource ID, when the subscription is known
+    pub fn id(&self) -> Option<AzureResourceId> {
+        Some(self.account.id()?.child("gremlinDatabases", &self.name))
+    }
+
+    /// Shows the database
+    pub async fn show(&self) -> Result<CosmosDatabase> {
+        let account = &self.account;
+        cosmos::show_gremlin_database(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets the database throughput settings
+    pub async fn throughput(&self) -> Result<ThroughputSettings> {
+        let account = &self.account;
+        cosmos::get_gremlin_database_throughput(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Lists graphs
+    pub async fn graphs(&self) -> Result<Vec<CosmosContainer>> {
+        let account = &self.account;
+        cosmos::list_gremlin_graphs(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets a handle to a graph
+    pub fn graph(&self, name: &str) -> GraphRef<'a> {
+        GraphRef {
+            database: self.clone(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Handle to a Gremlin graph
+#[derive(Debug, Clone)]
+pub struct GraphRef<'a> {
+    database: GremlinDatabaseRef<'a>,
+    name: String,
+}
+
+impl<'a> GraphRef<'a> {
+    /// Creates a handle from a Gremlin graph resource ID
+    pub fn from_id(client: &'a AzureClient, id: &AzureResourceId) -> Result<Self> {
+        id.expect_type(&format!("{}/databaseAccounts/gremlinDatabases/graphs", COSMOS_DB_PROVIDER))?;
+        let account = AccountRef::new(client, id.require_segment("resourceGroups")?, id.require_segment("databaseAccounts")?);
+        let database = account.in_subscription(id.subscription_id()).gremlin_database(id.require_segment("gremlinDatabases")?);
+        Ok(database.graph(id.name()))
+    }
+
+    /// Gets the graph name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the database the graph belongs to
+    pub fn database(&self) -> &GremlinDatabaseRef<'a> {
+        &self.database
+    }
+
+    /// Gets the graph resource ID, when the subscription is known
+    pub fn id(&self) -> Option<AzureResourceId> {
+        Some(self.database.id()?.child("graphs", &self.name))
+    }
+
+    /// Shows the graph
+    pub async fn show(&self) -> Result<CosmosContainer> {
+        let account = &self.database.account;
+        cosmos::show_gremlin_graph(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets the graph throughput settings
+    pub async fn throughput(&self) -> Result<ThroughputSettings> {
+        let account = &self.database.account;
+        cosmos::get_gremlin_graph_throughput(account.client.context(), &account.name, &account.resource_group, &self.database.name, &self.name, account.subscription_id()).await
+    }
+}
+
+/// Handle to a Table API table
+#[derive(Debug, Clone)]
+pub struct TableRef<'a> {
+    account: AccountRef<'a>,
+    name: String,
+}
+
+impl<'a> TableRef<'a> {
+    /// Creates a handle from a Table API table resource ID
+    pub fn from_id(client: &'a AzureClient, id: &AzureResourceId) -> Result<Self> {
+        id.expect_type(&format!("{}/databaseAccounts/tables", COSMOS_DB_PROVIDER))?;
+        let account = AccountRef::new(client, id.require_segment("resourceGroups")?, id.require_segment("databaseAccounts")?);
+        Ok(account.in_subscription(id.subscription_id()).table(id.name()))
+    }
+
+    /// Gets the table name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the account the table belongs to
+    pub fn account(&self) -> &AccountRef<'a> {
+        &self.account
+    }
+
+    /// Gets the table resource ID, when the subscription is known
+    pub fn id(&self) -> Option<AzureResourceId> {
+        Some(self.account.id()?.child("tables", &self.name))
+    }
+
+    /// Shows the table
+    pub async fn show(&self) -> Result<CosmosTable> {
+        let account = &self.account;
+        cosmos::show_table(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+
+    /// Gets the table throughput settings
+    pub async fn throughput(&self) -> Result<ThroughputSettings> {
+        let account = &self.account;
+        cosmos::get_table_throughput(account.client.context(), &account.name, &account.resource_group, &self.name, account.subscription_id()).await
+    }
+}
+
+impl CosmosAccount {
+    /// Gets a handle to this account, in the subscription it was listed from
+    pub fn handle<'a>(&self, client: &'a AzureClient) -> AccountRef<'a> {
+        AccountRef::new(client, &self.resource_group, &self.name).in_subscription(self.id.subscription_id())
+    }
+}
+
+impl CosmosDatabase {
+    /// Gets a handle to this SQL database
+    ///
+    /// Fails with [`AzureError::InvalidResourceId`](crate::AzureError::InvalidResourceId)
+    /// for MongoDB and Gremlin databases and Cassandra keyspaces.
+    pub fn handle<'a>(&self, client: &'a AzureClient) -> Result<DatabaseRef<'a>> {
+        DatabaseRef::from_id(client, &self.id)
+    }
+}
+
+impl CosmosContainer {
+    /// Gets a handle to this SQL container
+    ///
+    /// Fails with [`AzureError::InvalidResourceId`](crate::AzureError::InvalidResourceId)
+    /// for Gremlin graphs.
+    pub fn handle<'a>(&self, client: &'a AzureClient) -> Result<ContainerRef<'a>> {
+        ContainerRef::from_id(client, &self.id)
+    }
+}
+
+impl MongoCollection {
+    /// Gets a handle to this collection
+    pub fn handle<'a>(&self, client: &'a AzureClient) -> Result<MongoCollectionRef<'a>> {
+        MongoCollectionRef::from_id(client, &self.id)
+    }
+}
+
+impl CassandraTable {
+    /// Gets a handle to this table
+    pub fn handle<'a>(&self, client: &'a AzureClient) -> Result<CassandraTableRef<'a>> {
+        CassandraTableRef::from_id(client, &self.id)
+    }
+}
+
+impl CosmosTable {
+    /// Gets a handle to this table
+    pub fn handle<'a>(&self, client: &'a AzureClient) -> Result<TableRef<'a>> {
+        TableRef::from_id(client, &self.id)
+    }
+}
@@ -9,7 +9,10 @@
 //! - **Cosmos DB Accounts**: List and show account details
 //! - **Keys & Connection Strings**: Access read-only and master keys
 //! - **SQL API**: List databases and containers 
-//! - **MongoDB API**: List databases and collections
+//! - **MongoDB API**: List and show databases and collections, with shard keys and indexes
+//! - **Cassandra API**: List keyspaces and tables with their schema
+//! - **Gremlin API**: List databases and graphs
+//! - **Table API**: List tables
 //! - **Throughput**: Query throughput settings
 //! - **Subscriptions**: List and show subscription information
 //! - **Resource Groups**: List and show resource group details
@@ -20,9 +23,23 @@
 //! methods to create, update, or delete Azure resources, ensuring safe exploration and monitoring
 //! of your Azure environment without risk of accidental changes.
 //! 
+//! ## Resilience
+//! 
+//! Commands can be bounded with a timeout and transient failures (ARM throttling, 5xx
+//! responses, dropped connections) retried according to a [`RetryPolicy`] configured on
+//! the [`AzureClient`].
+//! 
+//! ## Testing Without Azure
+//! 
+//! Every command is dispatched through a [`CommandExecutor`](executor::CommandExecutor).
+//! [`AzureClient::new`] uses the real Azure CLI, while
+//! [`ScriptedExecutor`](executor::ScriptedExecutor) serves canned responses so code
+//! depending on the client can be unit-tested offline. The [`recording`] module records
+//! real responses into scrubbed fixture files and replays them deterministically.
+//! 
 //! ## Example
 //! 
-//! ```rust
+//! ```rust,no_run
 //! use rust_az_wrapper::AzureClient;
 //! 
 //! #[tokio::main]
@@ -45,9 +62,23 @@
 pub mod client;
 pub mod commands;
 pub mod error;
+pub mod executor;
+pub mod handles;
 pub mod models;
+pub mod recording;
+pub mod resource_id;
+pub mod retry;
+pub mod serialization;
 pub mod utils;
 
 pub use client::AzureClient;
-pub use error::{AzureError, Result};
-pub use models::*; 
\ No newline at end of file
+pub use error::{AzureError, CliErrorDetails, Result};
+pub use executor::{CommandExecutor, CommandOutput, ProcessExecutor, ScriptedExecutor};
+pub use handles::{
+    AccountRef, CassandraTableRef, ContainerRef, DatabaseRef, GraphRef, GremlinDatabaseRef, KeyspaceRef, MongoCollectionRef,
+    MongoDatabaseRef, TableRef,
+};
+pub use models::*;
+pub use resource_id::AzureResourceId;
+pub use retry::RetryPolicy;
+pub use serialization::SerializationStyle;
\ No newline at end of file
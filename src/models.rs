@@ -1,188 +1,847 @@
 //! Data models for Azure resources focused on Cosmos DB
 
+use crate::error::Result;
+use crate::resource_id::AzureResourceId;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::collections::HashMap;
+use std::fmt;
+
+/// Defines a string-valued enum with an `Unknown` fallback
+///
+/// Values are matched case-insensitively when parsing and serialize back to the name
+/// Azure uses, while unrecognized values round-trip unchanged through `Unknown`.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $( $(#[$variant_meta:meta])* $variant:ident => $value:literal, )+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(from = "String", into = "String")]
+        #[non_exhaustive]
+        pub enum $name {
+            $( $(#[$variant_meta])* $variant, )+
+            /// A value not known to this version of the library
+            Unknown(String),
+        }
+
+        impl $name {
+            /// Gets the name used by Azure
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( Self::$variant => $value, )+
+                    Self::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                $( if value.eq_ignore_ascii_case($value) { return Self::$variant; } )+
+                Self::Unknown(value)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                Self::from(value.to_string())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.as_str().to_string()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+/// Access to properties returned by Azure that this crate does not model
+///
+/// Every model keeps them in its `extra` map, so exporting or re-serializing a model is
+/// lossless even when the Azure CLI adds properties this crate does not know yet.
+pub trait ExtraFields {
+    /// Gets the unrecognized properties
+    fn extra(&self) -> &Map<String, Value>;
+
+    /// Gets an unrecognized property by its JSON name
+    fn extra_field(&self, name: &str) -> Option<&Value> {
+        self.extra().get(name)
+    }
+}
+
+/// Implements [`ExtraFields`] for models with an `extra` field
+macro_rules! impl_extra_fields {
+    ($($model:ty),+ $(,)?) => {
+        $(
+            impl ExtraFields for $model {
+                fn extra(&self) -> &Map<String, Value> {
+                    &self.extra
+                }
+            }
+        )+
+    };
+}
+
+/// Implements `rid`, `last_modified` and `etag` for models whose `resource` carries the
+/// Cosmos DB system properties (`_rid`, `_ts`, `_etag`)
+macro_rules! impl_system_properties {
+    ($($model:ty),+ $(,)?) => {
+        $(
+            impl $model {
+                /// Gets the internal resource ID (`_rid`)
+                pub fn rid(&self) -> Option<&str> {
+                    self.resource.as_ref()?.rid.as_deref()
+                }
+
+                /// Gets the last modification time (`_ts`)
+                pub fn last_modified(&self) -> Option<DateTime<Utc>> {
+                    DateTime::from_timestamp(self.resource.as_ref()?.ts?, 0)
+                }
+
+                /// Gets the entity tag (`_etag`)
+                pub fn etag(&self) -> Option<&str> {
+                    self.resource.as_ref()?.etag.as_deref()
+                }
+            }
+        )+
+    };
+}
 
 /// Azure Subscription information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Subscription {
-    /// Subscription ID
+    /// Subscription ID (the bare GUID `az account list` returns, not a resource path)
     pub id: String,
     /// Subscription name
-    #[serde(alias = "name")]
+    #[serde(rename = "name", alias = "displayName", alias = "display_name")]
     pub display_name: String,
     /// Subscription state
-    pub state: String,
-    /// Tenant ID
-    #[serde(alias = "tenantId")]
+    pub state: SubscriptionState,
+    /// Tenant the subscription is accessed through
+    #[serde(alias = "tenant_id")]
     pub tenant_id: String,
     /// Whether this is the default subscription
-    #[serde(alias = "isDefault")]
+    #[serde(alias = "is_default")]
     pub is_default: Option<bool>,
+    /// Tenant that owns the subscription
+    #[serde(alias = "home_tenant_id")]
+    pub home_tenant_id: Option<String>,
+    /// Display name of the tenant
+    #[serde(alias = "tenant_display_name")]
+    pub tenant_display_name: Option<String>,
+    /// Default domain of the tenant
+    #[serde(alias = "tenant_default_domain")]
+    pub tenant_default_domain: Option<String>,
+    /// Tenants granted access through Azure Lighthouse
+    #[serde(alias = "managed_by_tenants")]
+    pub managed_by_tenants: Option<Vec<ManagedByTenant>>,
+    /// Cloud the subscription belongs to (e.g. `AzureCloud`)
+    #[serde(alias = "cloud_name")]
+    pub cloud_name: Option<String>,
+    /// Cloud environment name, reported by older Azure CLI versions
+    #[serde(alias = "environment_name")]
+    pub environment_name: Option<String>,
+    /// Signed-in identity
+    pub user: Option<SubscriptionUser>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl Subscription {
+    /// Gets the tenant that owns the subscription, falling back to the access tenant
+    pub fn home_tenant(&self) -> &str {
+        self.home_tenant_id.as_deref().unwrap_or(&self.tenant_id)
+    }
+
+    /// Whether the subscription is reached through Azure Lighthouse delegation, i.e.
+    /// accessed from a tenant other than the one owning it
+    pub fn is_delegated(&self) -> bool {
+        !self.home_tenant().eq_ignore_ascii_case(&self.tenant_id)
+    }
+
+    /// Whether the given tenant manages the subscription through Azure Lighthouse
+    pub fn is_managed_by(&self, tenant_id: &str) -> bool {
+        self.managed_by_tenants
+            .iter()
+            .flatten()
+            .any(|tenant| tenant.tenant_id.eq_ignore_ascii_case(tenant_id))
+    }
+}
+
+string_enum! {
+    /// Subscription state
+    pub enum SubscriptionState {
+        /// Active subscription
+        Enabled => "Enabled",
+        /// Payment is overdue; the subscription will be disabled if not resolved
+        Warned => "Warned",
+        /// Payment is past due
+        PastDue => "PastDue",
+        /// Disabled; resources are deallocated
+        Disabled => "Disabled",
+        /// Canceled and pending deletion
+        Deleted => "Deleted",
+    }
+}
+
+/// Tenant managing a subscription through Azure Lighthouse
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedByTenant {
+    /// Managing tenant ID
+    pub tenant_id: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Identity the Azure CLI is signed in with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionUser {
+    /// User principal name or application ID
+    pub name: String,
+    /// Identity type
+    #[serde(rename = "type")]
+    pub user_type: SubscriptionUserType,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+string_enum! {
+    /// Kind of identity the Azure CLI is signed in with
+    pub enum SubscriptionUserType {
+        /// User account
+        User => "user",
+        /// Service principal or managed identity
+        ServicePrincipal => "servicePrincipal",
+    }
+}
+
+/// Azure AD tenant, as returned by `az account tenant list`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tenant {
+    /// Tenant path (`/tenants/{tenantId}`), which is not an ARM resource ID
+    pub id: String,
+    /// Tenant ID
+    #[serde(alias = "tenant_id")]
+    pub tenant_id: String,
+    /// Display name
+    #[serde(alias = "display_name")]
+    pub display_name: Option<String>,
+    /// Default domain
+    #[serde(alias = "default_domain")]
+    pub default_domain: Option<String>,
+    /// Verified domains
+    pub domains: Option<Vec<String>>,
+    /// Relationship to the signed-in identity (`Home`, `ProjectedBy`, `ManagedBy`)
+    #[serde(alias = "tenant_category")]
+    pub tenant_category: Option<String>,
+    /// Tenant type (e.g. `AAD`)
+    #[serde(alias = "tenant_type")]
+    pub tenant_type: Option<String>,
+    /// Country code
+    #[serde(alias = "country_code")]
+    pub country_code: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Azure region, as returned by `az account list-locations`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    /// Location ID
+    pub id: AzureResourceId,
+    /// Programmatic name (e.g. `eastus`)
+    pub name: String,
+    /// Display name (e.g. `East US`)
+    #[serde(alias = "display_name")]
+    pub display_name: String,
+    /// Display name including the geography (e.g. `(US) East US`)
+    #[serde(alias = "regional_display_name")]
+    pub regional_display_name: Option<String>,
+    /// Location type
+    #[serde(rename = "type", alias = "locationType", alias = "location_type")]
+    pub location_type: Option<LocationType>,
+    /// Region metadata
+    pub metadata: Option<LocationMetadata>,
+    /// Mapping of the subscription's logical zones to physical zones
+    #[serde(alias = "availability_zone_mappings")]
+    pub availability_zone_mappings: Option<Vec<AvailabilityZoneMapping>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl Location {
+    /// Whether `name` designates this location
+    ///
+    /// Accepts the programmatic name (`eastus`) as well as the display name (`East US`),
+    /// which is what Cosmos DB reports in `AccountLocation::location_name`.
+    pub fn matches(&self, name: &str) -> bool {
+        let name = normalize_location_name(name);
+        normalize_location_name(&self.name) == name || normalize_location_name(&self.display_name) == name
+    }
+
+    /// Gets the programmatic names of the paired regions
+    pub fn paired_regions(&self) -> Vec<&str> {
+        self.metadata
+            .iter()
+            .flat_map(|metadata| metadata.paired_region.iter().flatten())
+            .map(|region| region.name.as_str())
+            .collect()
+    }
+
+    /// Whether this region is paired with the region designated by `name`
+    pub fn is_paired_with(&self, name: &str) -> bool {
+        let name = normalize_location_name(name);
+        self.paired_regions().into_iter().any(|region| normalize_location_name(region) == name)
+    }
+
+    /// Whether this is a physical region, as opposed to a logical grouping such as `unitedstates`
+    pub fn is_physical(&self) -> bool {
+        self.metadata.as_ref().and_then(|metadata| metadata.region_type.as_ref()) == Some(&RegionType::Physical)
+    }
+
+    /// Whether the region has availability zones in this subscription
+    pub fn has_availability_zones(&self) -> bool {
+        self.availability_zone_mappings.as_ref().is_some_and(|mappings| !mappings.is_empty())
+    }
+
+    /// Gets the latitude and longitude, in degrees
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        let metadata = self.metadata.as_ref()?;
+        let latitude = metadata.latitude.as_deref()?.parse().ok()?;
+        let longitude = metadata.longitude.as_deref()?.parse().ok()?;
+        Some((latitude, longitude))
+    }
+}
+
+/// Region metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationMetadata {
+    /// Geography (e.g. `United States`)
+    pub geography: Option<String>,
+    /// Geography group (e.g. `US`)
+    #[serde(alias = "geography_group")]
+    pub geography_group: Option<String>,
+    /// Physical location (e.g. `Virginia`)
+    #[serde(alias = "physical_location")]
+    pub physical_location: Option<String>,
+    /// Latitude, in degrees, as reported by Azure
+    pub latitude: Option<String>,
+    /// Longitude, in degrees, as reported by Azure
+    pub longitude: Option<String>,
+    /// Paired regions
+    #[serde(alias = "paired_region")]
+    pub paired_region: Option<Vec<PairedRegion>>,
+    /// Region category
+    #[serde(alias = "region_category")]
+    pub region_category: Option<RegionCategory>,
+    /// Region type
+    #[serde(alias = "region_type")]
+    pub region_type: Option<RegionType>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Region paired with a location for disaster recovery
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PairedRegion {
+    /// Programmatic name of the paired region
+    pub name: String,
+    /// Location ID of the paired region
+    pub id: Option<AzureResourceId>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Mapping of a subscription's logical availability zone to a physical zone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvailabilityZoneMapping {
+    /// Logical zone, as used in deployments (e.g. `1`)
+    #[serde(alias = "logical_zone")]
+    pub logical_zone: String,
+    /// Physical zone (e.g. `eastus-az1`)
+    #[serde(alias = "physical_zone")]
+    pub physical_zone: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+string_enum! {
+    /// Location type
+    pub enum LocationType {
+        /// Azure region
+        Region => "Region",
+        /// Azure Edge Zone
+        EdgeZone => "EdgeZone",
+    }
+}
+
+string_enum! {
+    /// Region type
+    pub enum RegionType {
+        /// A datacenter region
+        Physical => "Physical",
+        /// A grouping of regions, such as a geography
+        Logical => "Logical",
+    }
+}
+
+string_enum! {
+    /// Region category
+    pub enum RegionCategory {
+        /// Recommended for most workloads
+        Recommended => "Recommended",
+        /// Alternate region, typically used as a pair
+        Other => "Other",
+        /// Extended region with restricted access
+        Extended => "Extended",
+    }
+}
+
+/// Normalizes a location or display name for comparison (`East US` → `eastus`)
+fn normalize_location_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
 }
 
 /// Resource Group information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ResourceGroup {
     /// Resource group name
     pub name: String,
     /// Resource group ID
-    pub id: String,
+    pub id: AzureResourceId,
     /// Resource group location
     pub location: String,
+    /// Resource type (`Microsoft.Resources/resourceGroups`)
+    #[serde(rename = "type", alias = "resourceType", alias = "resource_type")]
+    pub resource_type: Option<String>,
+    /// ID of the resource managing this group (e.g. an AKS cluster or managed application)
+    #[serde(alias = "managed_by")]
+    pub managed_by: Option<String>,
     /// Associated tags
     pub tags: Option<HashMap<String, String>>,
     /// Provisioning state within properties
     pub properties: Option<ResourceGroupProperties>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl ResourceGroup {
+    /// Gets the provisioning state
+    pub fn provisioning_state(&self) -> Option<&ProvisioningState> {
+        Some(&self.properties.as_ref()?.provisioning_state)
+    }
+
+    /// Whether the group is managed by another resource
+    pub fn is_managed(&self) -> bool {
+        self.managed_by.is_some()
+    }
+
+    /// Gets the value of a tag
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags.as_ref()?.get(key).map(String::as_str)
+    }
 }
 
 /// Resource Group properties
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ResourceGroupProperties {
     /// Provisioning state
-    #[serde(alias = "provisioningState", alias = "provisioning_state")]
-    pub provisioning_state: String,
+    #[serde(alias = "provisioning_state")]
+    pub provisioning_state: ProvisioningState,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Any Azure resource, as returned by `az resource list`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenericResource {
+    /// Resource name
+    pub name: String,
+    /// Resource ID
+    pub id: AzureResourceId,
+    /// Resource type (e.g. `Microsoft.DocumentDB/databaseAccounts`)
+    #[serde(rename = "type", alias = "resourceType", alias = "resource_type")]
+    pub resource_type: String,
+    /// Resource location
+    pub location: Option<String>,
+    /// Resource kind, for resource types that have one
+    pub kind: Option<String>,
+    /// Resource group
+    #[serde(alias = "resource_group")]
+    pub resource_group: Option<String>,
+    /// ID of the resource managing this one
+    #[serde(alias = "managed_by")]
+    pub managed_by: Option<String>,
+    /// Provisioning state
+    #[serde(alias = "provisioning_state")]
+    pub provisioning_state: Option<ProvisioningState>,
+    /// SKU
+    pub sku: Option<ResourceSku>,
+    /// Managed identity
+    pub identity: Option<AccountIdentity>,
+    /// Creation time
+    #[serde(alias = "created_time")]
+    pub created_time: Option<String>,
+    /// Last change time
+    #[serde(alias = "changed_time")]
+    pub changed_time: Option<String>,
+    /// Associated tags
+    pub tags: Option<HashMap<String, String>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl GenericResource {
+    /// Whether the resource has the given type, compared case-insensitively
+    pub fn is_type(&self, resource_type: &str) -> bool {
+        self.resource_type.eq_ignore_ascii_case(resource_type)
+    }
+}
+
+/// Resource SKU
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceSku {
+    /// SKU name
+    pub name: Option<String>,
+    /// SKU tier
+    pub tier: Option<String>,
+    /// SKU size
+    pub size: Option<String>,
+    /// SKU family
+    pub family: Option<String>,
+    /// SKU capacity
+    pub capacity: Option<i64>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Cosmos DB Account information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CosmosAccount {
     /// Account name
     pub name: String,
     /// Resource ID
-    pub id: String,
+    pub id: AzureResourceId,
     /// Account location
     pub location: String,
     /// Resource group
-    #[serde(alias = "resourceGroup", alias = "resource_group")]
+    #[serde(alias = "resource_group")]
     pub resource_group: String,
     /// Resource type
     #[serde(rename = "type", alias = "resourceType", alias = "resource_type")]
     pub resource_type: String,
     /// Account type (SQL, MongoDB, etc.)
-    pub kind: String,
+    pub kind: AccountKind,
     /// Provisioning status
-    #[serde(alias = "provisioningState", alias = "provisioning_state")]
-    pub provisioning_state: String,
+    #[serde(alias = "provisioning_state")]
+    pub provisioning_state: ProvisioningState,
     /// Endpoint URI
-    #[serde(alias = "documentEndpoint", alias = "document_endpoint")]
+    #[serde(alias = "document_endpoint")]
     pub document_endpoint: String,
     /// Associated tags
     pub tags: Option<HashMap<String, String>>,
     
     // Cosmos DB specific fields directly at root level
     /// Backup policy
-    #[serde(alias = "backupPolicy")]
+    #[serde(alias = "backup_policy")]
     pub backup_policy: Option<BackupPolicy>,
     /// Analytical storage configuration
-    #[serde(alias = "analyticalStorageConfiguration")]
+    #[serde(alias = "analytical_storage_configuration")]
     pub analytical_storage_configuration: Option<AnalyticalStorageConfiguration>,
     /// API properties
-    #[serde(alias = "apiProperties")]
+    #[serde(alias = "api_properties")]
     pub api_properties: Option<ApiProperties>,
     /// Account capabilities
     pub capabilities: Option<Vec<Capability>>,
     /// Capacity configuration
     pub capacity: Option<CapacitySettings>,
     /// Consistency policy
-    #[serde(alias = "consistencyPolicy")]
+    #[serde(alias = "consistency_policy")]
     pub consistency_policy: Option<ConsistencyPolicy>,
     /// CORS settings
     pub cors: Option<Vec<CorsPolicy>>,
     /// Creation mode
-    #[serde(alias = "createMode")]
+    #[serde(alias = "create_mode")]
     pub create_mode: Option<String>,
     /// Customer managed key status
-    #[serde(alias = "customerManagedKeyStatus")]
+    #[serde(alias = "customer_managed_key_status")]
     pub customer_managed_key_status: Option<String>,
     /// Database account offer type
-    #[serde(alias = "databaseAccountOfferType")]
+    #[serde(alias = "database_account_offer_type")]
     pub database_account_offer_type: Option<String>,
     /// Default identity
-    #[serde(alias = "defaultIdentity")]
+    #[serde(alias = "default_identity")]
     pub default_identity: Option<String>,
     /// Whether key-based metadata write access is disabled
-    #[serde(alias = "disableKeyBasedMetadataWriteAccess")]
+    #[serde(alias = "disable_key_based_metadata_write_access")]
     pub disable_key_based_metadata_write_access: Option<bool>,
     /// Whether local authentication is disabled
-    #[serde(alias = "disableLocalAuth")]
+    #[serde(alias = "disable_local_auth")]
     pub disable_local_auth: Option<bool>,
     /// Whether analytical storage is enabled
-    #[serde(alias = "enableAnalyticalStorage")]
+    #[serde(alias = "enable_analytical_storage")]
     pub enable_analytical_storage: Option<bool>,
     /// Whether automatic failover is enabled
-    #[serde(alias = "enableAutomaticFailover")]
+    #[serde(alias = "enable_automatic_failover")]
     pub enable_automatic_failover: Option<bool>,
     /// Whether burst capacity is enabled
-    #[serde(alias = "enableBurstCapacity")]
+    #[serde(alias = "enable_burst_capacity")]
     pub enable_burst_capacity: Option<bool>,
     /// Whether Cassandra connector is enabled
-    #[serde(alias = "enableCassandraConnector")]
+    #[serde(alias = "enable_cassandra_connector")]
     pub enable_cassandra_connector: Option<bool>,
     /// Whether free tier is enabled
-    #[serde(alias = "enableFreeTier")]
+    #[serde(alias = "enable_free_tier")]
     pub enable_free_tier: Option<bool>,
     /// Whether multiple write locations are enabled
-    #[serde(alias = "enableMultipleWriteLocations")]
+    #[serde(alias = "enable_multiple_write_locations")]
     pub enable_multiple_write_locations: Option<bool>,
     /// Whether partition merge is enabled
-    #[serde(alias = "enablePartitionMerge")]
+    #[serde(alias = "enable_partition_merge")]
     pub enable_partition_merge: Option<bool>,
     /// Whether per-region per-partition autoscale is enabled
-    #[serde(alias = "enablePerRegionPerPartitionAutoscale")]
+    #[serde(alias = "enable_per_region_per_partition_autoscale")]
     pub enable_per_region_per_partition_autoscale: Option<bool>,
     /// Failover policies
-    #[serde(alias = "failoverPolicies")]
+    #[serde(alias = "failover_policies")]
     pub failover_policies: Option<Vec<FailoverPolicy>>,
     /// Identity configuration
     pub identity: Option<AccountIdentity>,
     /// Instance ID
-    #[serde(alias = "instanceId")]
+    #[serde(alias = "instance_id")]
     pub instance_id: Option<String>,
     /// IP rules
-    #[serde(alias = "ipRules")]
+    #[serde(alias = "ip_rules")]
     pub ip_rules: Option<Vec<IpRule>>,
     /// Whether virtual network filter is enabled
-    #[serde(alias = "isVirtualNetworkFilterEnabled")]
+    #[serde(alias = "is_virtual_network_filter_enabled")]
     pub is_virtual_network_filter_enabled: Option<bool>,
     /// Key Vault key URI
-    #[serde(alias = "keyVaultKeyUri")]
+    #[serde(alias = "key_vault_key_uri")]
     pub key_vault_key_uri: Option<String>,
     /// Keys metadata
-    #[serde(alias = "keysMetadata")]
+    #[serde(alias = "keys_metadata")]
     pub keys_metadata: Option<KeysMetadata>,
     /// Account locations
     pub locations: Option<Vec<AccountLocation>>,
     /// Minimal TLS version
-    #[serde(alias = "minimalTlsVersion")]
+    #[serde(alias = "minimal_tls_version")]
     pub minimal_tls_version: Option<String>,
     /// Network ACL bypass
-    #[serde(alias = "networkAclBypass")]
+    #[serde(alias = "network_acl_bypass")]
     pub network_acl_bypass: Option<String>,
-    /// Resource IDs for network ACL bypass
-    #[serde(alias = "networkAclBypassResourceIds")]
+    /// Resource IDs for network ACL bypass, unparsed like other nested IDs (see
+    /// [`PrivateEndpoint`]); [`AzureResourceId::parse`] validates them on demand
+    #[serde(alias = "network_acl_bypass_resource_ids")]
     pub network_acl_bypass_resource_ids: Option<Vec<String>>,
     /// Private endpoint connections
-    #[serde(alias = "privateEndpointConnections")]
+    #[serde(alias = "private_endpoint_connections")]
     pub private_endpoint_connections: Option<Vec<PrivateEndpointConnection>>,
     /// Public network access
-    #[serde(alias = "publicNetworkAccess")]
-    pub public_network_access: Option<String>,
+    #[serde(alias = "public_network_access")]
+    pub public_network_access: Option<PublicNetworkAccess>,
     /// Read locations
-    #[serde(alias = "readLocations")]
+    #[serde(alias = "read_locations")]
     pub read_locations: Option<Vec<AccountLocation>>,
     /// Restore parameters
-    #[serde(alias = "restoreParameters")]
+    #[serde(alias = "restore_parameters")]
     pub restore_parameters: Option<RestoreParameters>,
     /// System data
-    #[serde(alias = "systemData")]
+    #[serde(alias = "system_data")]
     pub system_data: Option<SystemData>,
     /// Virtual network rules
-    #[serde(alias = "virtualNetworkRules")]
+    #[serde(alias = "virtual_network_rules")]
     pub virtual_network_rules: Option<Vec<VirtualNetworkRule>>,
     /// Write locations
-    #[serde(alias = "writeLocations")]
+    #[serde(alias = "write_locations")]
     pub write_locations: Option<Vec<AccountLocation>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl CosmosAccount {
+    /// Whether the account has a capability (e.g. `EnableServerless`)
+    pub fn has_capability(&self, name: &str) -> bool {
+        self.capabilities
+            .iter()
+            .flatten()
+            .any(|capability| capability.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Gets the API the account serves, from its kind and capabilities
+    pub fn api_kind(&self) -> ApiKind {
+        if self.kind == AccountKind::MongoDB || self.has_capability("EnableMongo") {
+            ApiKind::MongoDB
+        } else if self.has_capability("EnableCassandra") {
+            ApiKind::Cassandra
+        } else if self.has_capability("EnableGremlin") {
+            ApiKind::Gremlin
+        } else if self.has_capability("EnableTable") {
+            ApiKind::Table
+        } else {
+            ApiKind::Sql
+        }
+    }
+
+    /// Whether the account is serverless (billed per request, without provisioned throughput)
+    pub fn is_serverless(&self) -> bool {
+        self.has_capability("EnableServerless")
+    }
+}
+
+/// API served by a Cosmos DB account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiKind {
+    /// NoSQL (`az cosmosdb sql`)
+    Sql,
+    /// API for MongoDB (`az cosmosdb mongodb`)
+    MongoDB,
+    /// Apache Cassandra (`az cosmosdb cassandra`)
+    Cassandra,
+    /// Apache Gremlin (`az cosmosdb gremlin`)
+    Gremlin,
+    /// Table (`az cosmosdb table`)
+    Table,
+}
+
+impl ApiKind {
+    /// Gets the API name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKind::Sql => "SQL",
+            ApiKind::MongoDB => "MongoDB",
+            ApiKind::Cassandra => "Cassandra",
+            ApiKind::Gremlin => "Gremlin",
+            ApiKind::Table => "Table",
+        }
+    }
+}
+
+impl std::fmt::Display for ApiKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+string_enum! {
+    /// Cosmos DB account kind
+    pub enum AccountKind {
+        /// NoSQL (and Cassandra, Gremlin and Table) accounts
+        GlobalDocumentDB => "GlobalDocumentDB",
+        /// API for MongoDB accounts
+        MongoDB => "MongoDB",
+        /// Parse accounts (retired)
+        Parse => "Parse",
+    }
+}
+
+string_enum! {
+    /// Azure resource provisioning state
+    pub enum ProvisioningState {
+        /// Provisioning completed
+        Succeeded => "Succeeded",
+        /// Provisioning failed
+        Failed => "Failed",
+        /// Provisioning was canceled
+        Canceled => "Canceled",
+        /// Request accepted, not yet started
+        Accepted => "Accepted",
+        /// Resource is being created
+        Creating => "Creating",
+        /// Resource is being updated
+        Updating => "Updating",
+        /// Resource is being deleted
+        Deleting => "Deleting",
+    }
+}
+
+string_enum! {
+    /// Public network access setting
+    pub enum PublicNetworkAccess {
+        /// Reachable from public networks (subject to firewall rules)
+        Enabled => "Enabled",
+        /// Reachable only through private endpoints
+        Disabled => "Disabled",
+        /// Access governed by a network security perimeter
+        SecuredByPerimeter => "SecuredByPerimeter",
+    }
+}
+
+string_enum! {
+    /// Backup mode
+    pub enum BackupType {
+        /// Periodic snapshots
+        Periodic => "Periodic",
+        /// Continuous backup with point-in-time restore
+        Continuous => "Continuous",
+    }
+}
+
+string_enum! {
+    /// Continuous backup retention tier
+    pub enum ContinuousBackupTier {
+        /// 7-day retention
+        Continuous7Days => "Continuous7Days",
+        /// 30-day retention
+        Continuous30Days => "Continuous30Days",
+    }
+}
+
+string_enum! {
+    /// Default consistency level
+    pub enum ConsistencyLevel {
+        /// Eventual consistency
+        Eventual => "Eventual",
+        /// Consistent prefix
+        ConsistentPrefix => "ConsistentPrefix",
+        /// Session consistency
+        Session => "Session",
+        /// Bounded staleness
+        BoundedStaleness => "BoundedStaleness",
+        /// Strong consistency
+        Strong => "Strong",
+    }
 }
 
 /// Analytical storage configuration
@@ -191,6 +850,9 @@ pub struct CosmosAccount {
 pub struct AnalyticalStorageConfiguration {
     /// Schema type
     pub schema_type: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// API properties
@@ -199,13 +861,20 @@ pub struct AnalyticalStorageConfiguration {
 pub struct ApiProperties {
     /// Server version (for MongoDB)
     pub server_version: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Account capability
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Capability {
     /// Capability name
     pub name: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Backup policy
@@ -214,13 +883,16 @@ pub struct Capability {
 pub struct BackupPolicy {
     /// Backup type
     #[serde(rename = "type")]
-    pub backup_type: String,
+    pub backup_type: BackupType,
     /// Migration state
     pub migration_state: Option<String>,
     /// Periodic mode properties
     pub periodic_mode_properties: Option<PeriodicBackupProperties>,
     /// Continuous mode properties
     pub continuous_mode_properties: Option<ContinuousBackupProperties>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Periodic backup properties
@@ -233,14 +905,20 @@ pub struct PeriodicBackupProperties {
     pub backup_retention_interval_in_hours: Option<i32>,
     /// Backup storage redundancy
     pub backup_storage_redundancy: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Continuous backup properties
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ContinuousBackupProperties {
-    /// Continuous backup tier (e.g., Continuous7Days, Continuous30Days)
-    pub tier: Option<String>,
+    /// Continuous backup tier
+    pub tier: Option<ContinuousBackupTier>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Failover policy
@@ -249,10 +927,13 @@ pub struct ContinuousBackupProperties {
 pub struct FailoverPolicy {
     /// Failover priority
     pub failover_priority: i32,
-    /// Location ID
+    /// Location ID, `{account}-{region}` rather than an ARM resource ID
     pub id: Option<String>,
     /// Location name
     pub location_name: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Account identity
@@ -268,6 +949,9 @@ pub struct AccountIdentity {
     pub identity_type: Option<String>,
     /// User assigned identities
     pub user_assigned_identities: Option<HashMap<String, serde_json::Value>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// IP rule
@@ -276,6 +960,9 @@ pub struct AccountIdentity {
 pub struct IpRule {
     /// IP address or range
     pub ip_address_or_range: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Keys metadata
@@ -290,6 +977,9 @@ pub struct KeysMetadata {
     pub secondary_master_key: Option<KeyMetadata>,
     /// Secondary readonly master key
     pub secondary_readonly_master_key: Option<KeyMetadata>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Key metadata
@@ -298,13 +988,16 @@ pub struct KeysMetadata {
 pub struct KeyMetadata {
     /// Generation time
     pub generation_time: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Private endpoint connection
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateEndpointConnection {
-    /// Connection ID
+    /// Connection resource ID, see [`PrivateEndpointConnection::resource_id`]
     pub id: Option<String>,
     /// Connection name
     pub name: Option<String>,
@@ -321,9 +1014,23 @@ pub struct PrivateEndpointConnection {
     pub provisioning_state: Option<String>,
     /// Resource group
     pub resource_group: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl PrivateEndpointConnection {
+    /// Parses the connection ID, if present
+    pub fn resource_id(&self) -> Option<Result<AzureResourceId>> {
+        self.id.as_deref().map(AzureResourceId::parse)
+    }
 }
 
 /// Private endpoint
+///
+/// IDs nested inside an account point at resources in other providers and are kept as
+/// strings, so one malformed entry doesn't fail the whole account listing; they are
+/// validated when parsed with [`PrivateEndpoint::resource_id`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateEndpoint {
@@ -331,6 +1038,16 @@ pub struct PrivateEndpoint {
     pub id: String,
     /// Resource group
     pub resource_group: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl PrivateEndpoint {
+    /// Parses the private endpoint ID
+    pub fn resource_id(&self) -> Result<AzureResourceId> {
+        AzureResourceId::parse(&self.id)
+    }
 }
 
 /// Private link service connection state
@@ -343,6 +1060,9 @@ pub struct PrivateLinkServiceConnectionState {
     pub description: Option<String>,
     /// Status
     pub status: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Restore parameters
@@ -355,6 +1075,9 @@ pub struct RestoreParameters {
     pub restore_source: Option<String>,
     /// Restore timestamp in UTC
     pub restore_timestamp_in_utc: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// System data
@@ -373,18 +1096,31 @@ pub struct SystemData {
     pub last_modified_by: Option<String>,
     /// Last modified by type
     pub last_modified_by_type: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Virtual network rule
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VirtualNetworkRule {
-    /// Virtual network ID
+    /// Subnet ID, unparsed like other nested IDs (see [`PrivateEndpoint`])
     pub id: String,
     /// Whether to ignore missing VNet service endpoint
     pub ignore_missing_vnet_service_endpoint: Option<bool>,
     /// Resource group
     pub resource_group: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl VirtualNetworkRule {
+    /// Parses the subnet ID
+    pub fn resource_id(&self) -> Result<AzureResourceId> {
+        AzureResourceId::parse(&self.id)
+    }
 }
 
 /// Capacity settings
@@ -393,6 +1129,9 @@ pub struct VirtualNetworkRule {
 pub struct CapacitySettings {
     /// Total provisioned throughput limit
     pub total_throughput_limit: Option<i32>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Consistency policy
@@ -400,11 +1139,14 @@ pub struct CapacitySettings {
 #[serde(rename_all = "camelCase")]
 pub struct ConsistencyPolicy {
     /// Default consistency level
-    pub default_consistency_level: String,
+    pub default_consistency_level: ConsistencyLevel,
     /// Maximum tolerated staleness
     pub max_staleness_prefix: Option<i64>,
     /// Maximum staleness interval
     pub max_interval_in_seconds: Option<i32>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// CORS policy
@@ -421,6 +1163,9 @@ pub struct CorsPolicy {
     pub exposed_headers: String,
     /// Maximum age
     pub max_age_in_seconds: Option<i32>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Account location
@@ -435,32 +1180,195 @@ pub struct AccountLocation {
     pub failover_priority: i32,
     /// Whether it's a write region
     pub is_zone_redundant: Option<bool>,
-    /// Location ID
+    /// Location ID, `{account}-{region}` rather than an ARM resource ID
     pub id: Option<String>,
     /// Document endpoint URI
     pub document_endpoint: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl AccountLocation {
+    /// Finds the region metadata for this account location
+    pub fn resolve<'a>(&self, locations: &'a [Location]) -> Option<&'a Location> {
+        locations.iter().find(|location| location.matches(&self.location_name))
+    }
 }
 
 /// Cosmos DB Database information
+///
+/// Used for SQL, MongoDB and Gremlin API databases as well as Cassandra keyspaces, which
+/// share the same shape.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CosmosDatabase {
     /// Database name
     pub name: String,
     /// Database ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Location
+    pub location: Option<String>,
+    /// Associated tags
+    pub tags: Option<HashMap<String, String>>,
+    /// Database resource properties
+    pub resource: Option<DatabaseResource>,
+    /// Database options (dedicated throughput)
+    pub options: Option<ResourceOptions>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl CosmosDatabase {
+    /// Gets the manual throughput configured at creation, if any
+    pub fn throughput(&self) -> Option<i32> {
+        self.options.as_ref()?.throughput
+    }
+
+    /// Gets the autoscale settings configured at creation, if any
+    pub fn autoscale_settings(&self) -> Option<&AutoscaleSettings> {
+        self.options.as_ref()?.autoscale_settings.as_ref()
+    }
+}
+
+/// Database resource properties
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseResource {
+    /// Database name
     pub id: String,
-    /// Throughput settings
-    pub throughput_settings: Option<ThroughputSettings>,
+    /// Internal resource ID
+    #[serde(rename = "_rid")]
+    pub rid: Option<String>,
+    /// Last modification timestamp (Unix seconds)
+    #[serde(rename = "_ts")]
+    pub ts: Option<i64>,
+    /// Entity tag
+    #[serde(rename = "_etag")]
+    pub etag: Option<String>,
+    /// Self link
+    #[serde(rename = "_self")]
+    pub self_link: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Resource creation options
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceOptions {
+    /// Manual throughput
+    pub throughput: Option<i32>,
+    /// Autoscale settings
+    pub autoscale_settings: Option<AutoscaleSettings>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
-/// Throughput settings
+/// Throughput settings, as returned by `az cosmosdb ... throughput show`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ThroughputSettings {
+    /// Settings name (`default`)
+    pub name: String,
+    /// Settings ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Location
+    pub location: Option<String>,
+    /// Associated tags
+    pub tags: Option<HashMap<String, String>>,
+    /// Throughput resource properties
+    pub resource: Option<ThroughputSettingsResource>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl ThroughputSettings {
+    /// Gets the manual throughput (RU/s), or the current autoscale throughput
+    pub fn throughput(&self) -> Option<i32> {
+        self.resource.as_ref()?.throughput
+    }
+
+    /// Gets the autoscale settings, when autoscale is enabled
+    pub fn autoscale_settings(&self) -> Option<&AutoscaleSettings> {
+        self.resource.as_ref()?.autoscale_settings.as_ref()
+    }
+
+    /// Whether throughput scales automatically
+    pub fn is_autoscale(&self) -> bool {
+        self.autoscale_settings().is_some()
+    }
+
+    /// Gets the autoscale maximum throughput (RU/s)
+    pub fn max_throughput(&self) -> Option<i32> {
+        Some(self.autoscale_settings()?.max_throughput)
+    }
+
+    /// Gets the percentage autoscale raises the maximum by when auto-upgrade is enabled
+    pub fn autoscale_increment_percent(&self) -> Option<i32> {
+        self.autoscale_settings()?.auto_upgrade_policy.as_ref()?.throughput_policy.as_ref()?.increment_percent
+    }
+
+    /// Gets the lowest throughput (RU/s) the resource can be scaled down to
+    pub fn minimum_throughput(&self) -> Option<i32> {
+        self.resource.as_ref()?.minimum_throughput.as_deref()?.trim().parse().ok()
+    }
+
+    /// Whether a throughput change is still being applied
+    pub fn is_offer_replace_pending(&self) -> bool {
+        self.resource
+            .as_ref()
+            .and_then(|resource| resource.offer_replace_pending.as_deref())
+            .is_some_and(|pending| pending.trim().eq_ignore_ascii_case("true"))
+    }
+
+    /// Gets the highest throughput (RU/s) that can be applied without a pending offer replace
+    pub fn instant_maximum_throughput(&self) -> Option<i32> {
+        self.resource.as_ref()?.instant_maximum_throughput.as_deref()?.trim().parse().ok()
+    }
+
+    /// Gets the highest throughput (RU/s) allowed for the resource
+    pub fn soft_allowed_maximum_throughput(&self) -> Option<i64> {
+        self.resource.as_ref()?.soft_allowed_maximum_throughput.as_deref()?.trim().parse().ok()
+    }
+}
+
+/// Throughput resource properties
+///
+/// Azure reports the limits and the pending flag as strings (`"400"`, `"False"`);
+/// use the [`ThroughputSettings`] accessors for typed values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThroughputSettingsResource {
     /// Manual throughput
     pub throughput: Option<i32>,
     /// Autoscale settings
     pub autoscale_settings: Option<AutoscaleSettings>,
+    /// Minimum throughput
+    pub minimum_throughput: Option<String>,
+    /// Whether a throughput change is pending (`"True"`/`"False"`)
+    pub offer_replace_pending: Option<String>,
+    /// Maximum throughput applicable instantly
+    pub instant_maximum_throughput: Option<String>,
+    /// Maximum throughput allowed
+    pub soft_allowed_maximum_throughput: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Autoscale settings
@@ -469,68 +1377,1165 @@ pub struct ThroughputSettings {
 pub struct AutoscaleSettings {
     /// Maximum throughput
     pub max_throughput: i32,
+    /// Automatic raise of the maximum throughput
+    pub auto_upgrade_policy: Option<AutoUpgradePolicy>,
+    /// Maximum throughput being applied
+    pub target_max_throughput: Option<i32>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Autoscale auto-upgrade policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoUpgradePolicy {
+    /// Throughput policy
+    pub throughput_policy: Option<ThroughputPolicy>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Autoscale throughput policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThroughputPolicy {
+    /// Whether the maximum throughput is raised automatically
+    pub is_enabled: Option<bool>,
+    /// Percentage the maximum throughput is raised by
+    pub increment_percent: Option<i32>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Throughput available to a database or container
+#[derive(Debug, Clone)]
+pub enum ProvisionedThroughput {
+    /// Throughput provisioned on the resource itself
+    Dedicated(ThroughputSettings),
+    /// The container has no throughput of its own and shares its database's
+    SharedAtDatabase(ThroughputSettings),
+    /// No throughput is provisioned (a database whose containers provision their own)
+    NotProvisioned,
+    /// The account is serverless and has no provisioned throughput
+    Serverless,
+}
+
+impl ProvisionedThroughput {
+    /// Gets the settings of the throughput serving the resource, dedicated or shared
+    pub fn settings(&self) -> Option<&ThroughputSettings> {
+        match self {
+            ProvisionedThroughput::Dedicated(settings) | ProvisionedThroughput::SharedAtDatabase(settings) => Some(settings),
+            ProvisionedThroughput::NotProvisioned | ProvisionedThroughput::Serverless => None,
+        }
+    }
+
+    /// Whether the throughput is shared with the other containers of the database
+    pub fn is_shared(&self) -> bool {
+        matches!(self, ProvisionedThroughput::SharedAtDatabase(_))
+    }
 }
 
 /// Cosmos DB Container information
+///
+/// Used for both SQL containers and Gremlin graphs, which share the same shape.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CosmosContainer {
     /// Container name
     pub name: String,
     /// Container ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Location
+    pub location: Option<String>,
+    /// Associated tags
+    pub tags: Option<HashMap<String, String>>,
+    /// Container resource properties
+    pub resource: Option<ContainerResource>,
+    /// Container options (dedicated throughput)
+    pub options: Option<ResourceOptions>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl CosmosContainer {
+    /// Gets the partition key definition
+    pub fn partition_key(&self) -> Option<&PartitionKey> {
+        self.resource.as_ref()?.partition_key.as_ref()
+    }
+
+    /// Whether the container uses hierarchical partition keys (subpartitioning)
+    pub fn is_subpartitioned(&self) -> bool {
+        self.partition_key().is_some_and(PartitionKey::is_hierarchical)
+    }
+
+    /// Gets the indexing policy
+    pub fn indexing_policy(&self) -> Option<&IndexingPolicy> {
+        self.resource.as_ref()?.indexing_policy.as_ref()
+    }
+
+    /// Gets the default time-to-live in seconds (`-1` means items never expire by default)
+    pub fn default_ttl(&self) -> Option<i32> {
+        self.resource.as_ref()?.default_ttl
+    }
+
+    /// Gets the analytical store time-to-live in seconds
+    pub fn analytical_storage_ttl(&self) -> Option<i64> {
+        self.resource.as_ref()?.analytical_storage_ttl
+    }
+
+    /// Gets the unique key constraints (empty when there are none)
+    pub fn unique_keys(&self) -> &[UniqueKey] {
+        self.resource
+            .as_ref()
+            .and_then(|resource| resource.unique_key_policy.as_ref())
+            .map_or(&[], |policy| policy.unique_keys.as_slice())
+    }
+
+    /// Gets the conflict resolution policy
+    pub fn conflict_resolution_policy(&self) -> Option<&ConflictResolutionPolicy> {
+        self.resource.as_ref()?.conflict_resolution_policy.as_ref()
+    }
+
+    /// Gets the computed properties (empty when there are none)
+    pub fn computed_properties(&self) -> &[ComputedProperty] {
+        self.resource
+            .as_ref()
+            .and_then(|resource| resource.computed_properties.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Gets the vector embedding policy
+    pub fn vector_embedding_policy(&self) -> Option<&VectorEmbeddingPolicy> {
+        self.resource.as_ref()?.vector_embedding_policy.as_ref()
+    }
+
+    /// Gets the full-text search policy
+    pub fn full_text_policy(&self) -> Option<&FullTextPolicy> {
+        self.resource.as_ref()?.full_text_policy.as_ref()
+    }
+
+    /// Gets the client-side encryption policy
+    pub fn client_encryption_policy(&self) -> Option<&ClientEncryptionPolicy> {
+        self.resource.as_ref()?.client_encryption_policy.as_ref()
+    }
+
+    /// Gets the manual throughput configured at creation, if any
+    pub fn throughput(&self) -> Option<i32> {
+        self.options.as_ref()?.throughput
+    }
+
+    /// Gets the autoscale settings configured at creation, if any
+    pub fn autoscale_settings(&self) -> Option<&AutoscaleSettings> {
+        self.options.as_ref()?.autoscale_settings.as_ref()
+    }
+}
+
+/// SQL container resource properties
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerResource {
+    /// Container name
     pub id: String,
     /// Partition key
     pub partition_key: Option<PartitionKey>,
-    /// Throughput settings
-    pub throughput_settings: Option<ThroughputSettings>,
     /// Indexing policy
     pub indexing_policy: Option<IndexingPolicy>,
+    /// Default time-to-live in seconds
+    pub default_ttl: Option<i32>,
+    /// Analytical store time-to-live in seconds
+    pub analytical_storage_ttl: Option<i64>,
+    /// Unique key policy
+    pub unique_key_policy: Option<UniqueKeyPolicy>,
+    /// Conflict resolution policy
+    pub conflict_resolution_policy: Option<ConflictResolutionPolicy>,
+    /// Computed properties
+    pub computed_properties: Option<Vec<ComputedProperty>>,
+    /// Vector embedding policy
+    pub vector_embedding_policy: Option<VectorEmbeddingPolicy>,
+    /// Full-text search policy
+    pub full_text_policy: Option<FullTextPolicy>,
+    /// Client-side encryption policy
+    pub client_encryption_policy: Option<ClientEncryptionPolicy>,
+    /// Internal resource ID
+    #[serde(rename = "_rid")]
+    pub rid: Option<String>,
+    /// Last modification timestamp (Unix seconds)
+    #[serde(rename = "_ts")]
+    pub ts: Option<i64>,
+    /// Entity tag
+    #[serde(rename = "_etag")]
+    pub etag: Option<String>,
+    /// Self link
+    #[serde(rename = "_self")]
+    pub self_link: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
-/// Partition key
+/// Unique key policy
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PartitionKey {
-    /// Partition key paths
+pub struct UniqueKeyPolicy {
+    /// Unique keys
+    pub unique_keys: Vec<UniqueKey>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Unique key constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniqueKey {
+    /// Paths that must be unique together within a logical partition
     pub paths: Vec<String>,
-    /// Partition key type
-    pub kind: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
-/// Indexing policy
+/// Conflict resolution policy
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct IndexingPolicy {
-    /// Whether automatic indexing is enabled
-    pub automatic: Option<bool>,
-    /// Indexing mode
-    pub indexing_mode: Option<String>,
-    /// Included paths
-    pub included_paths: Option<Vec<IndexPath>>,
-    /// Excluded paths
-    pub excluded_paths: Option<Vec<IndexPath>>,
+pub struct ConflictResolutionPolicy {
+    /// Conflict resolution mode (`LastWriterWins` or `Custom`)
+    pub mode: Option<String>,
+    /// Path compared in `LastWriterWins` mode
+    pub conflict_resolution_path: Option<String>,
+    /// Stored procedure used in `Custom` mode
+    pub conflict_resolution_procedure: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
-/// Index path
+/// Computed property
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct IndexPath {
-    /// Path
-    pub path: String,
+pub struct ComputedProperty {
+    /// Property name
+    pub name: String,
+    /// Query defining the property value
+    pub query: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
-/// Cosmos Account access keys
+/// Vector embedding policy
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CosmosKeys {
-    /// Primary master key
-    pub primary_master_key: String,
-    /// Secondary master key
-    pub secondary_master_key: String,
+pub struct VectorEmbeddingPolicy {
+    /// Vector embeddings
+    pub vector_embeddings: Vec<VectorEmbedding>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Vector embedding definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorEmbedding {
+    /// Path of the vector property
+    pub path: String,
+    /// Vector component type (e.g. `float32`)
+    pub data_type: String,
+    /// Distance function (e.g. `cosine`)
+    pub distance_function: String,
+    /// Number of dimensions
+    pub dimensions: u32,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Full-text search policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullTextPolicy {
+    /// Default language for full-text paths
+    pub default_language: Option<String>,
+    /// Full-text paths
+    pub full_text_paths: Vec<FullTextPath>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Full-text path definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullTextPath {
+    /// Path
+    pub path: String,
+    /// Language, when different from the default
+    pub language: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Client-side encryption policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientEncryptionPolicy {
+    /// Encrypted paths
+    pub included_paths: Vec<ClientEncryptionIncludedPath>,
+    /// Policy format version
+    pub policy_format_version: Option<i32>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Client-side encrypted path
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientEncryptionIncludedPath {
+    /// Path
+    pub path: String,
+    /// Client encryption key ID
+    pub client_encryption_key_id: String,
+    /// Encryption type (`Deterministic` or `Randomized`)
+    pub encryption_type: String,
+    /// Encryption algorithm
+    pub encryption_algorithm: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// SQL stored procedure information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredProcedure {
+    /// Stored procedure name
+    pub name: String,
+    /// Stored procedure ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Location
+    pub location: Option<String>,
+    /// Associated tags
+    pub tags: Option<HashMap<String, String>>,
+    /// Stored procedure resource properties
+    pub resource: Option<StoredProcedureResource>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl StoredProcedure {
+    /// Gets the JavaScript body
+    pub fn body(&self) -> Option<&str> {
+        self.resource.as_ref()?.body.as_deref()
+    }
+}
+
+/// Stored procedure resource properties
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredProcedureResource {
+    /// Stored procedure name
+    pub id: String,
+    /// JavaScript body
+    pub body: Option<String>,
+    /// Internal resource ID
+    #[serde(rename = "_rid")]
+    pub rid: Option<String>,
+    /// Last modification timestamp (Unix seconds)
+    #[serde(rename = "_ts")]
+    pub ts: Option<i64>,
+    /// Entity tag
+    #[serde(rename = "_etag")]
+    pub etag: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// SQL trigger information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trigger {
+    /// Trigger name
+    pub name: String,
+    /// Trigger ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Location
+    pub location: Option<String>,
+    /// Associated tags
+    pub tags: Option<HashMap<String, String>>,
+    /// Trigger resource properties
+    pub resource: Option<TriggerResource>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl Trigger {
+    /// Gets the JavaScript body
+    pub fn body(&self) -> Option<&str> {
+        self.resource.as_ref()?.body.as_deref()
+    }
+
+    /// Gets whether the trigger runs before or after the operation
+    pub fn trigger_type(&self) -> Option<&TriggerType> {
+        self.resource.as_ref()?.trigger_type.as_ref()
+    }
+
+    /// Gets the operation that fires the trigger
+    pub fn trigger_operation(&self) -> Option<&TriggerOperation> {
+        self.resource.as_ref()?.trigger_operation.as_ref()
+    }
+
+    /// Whether the trigger fires for an operation (`All` triggers fire for every operation)
+    pub fn fires_on(&self, operation: &TriggerOperation) -> bool {
+        match self.trigger_operation() {
+            Some(TriggerOperation::All) => true,
+            Some(trigger_operation) => trigger_operation == operation,
+            None => false,
+        }
+    }
+}
+
+/// Trigger resource properties
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerResource {
+    /// Trigger name
+    pub id: String,
+    /// JavaScript body
+    pub body: Option<String>,
+    /// Whether the trigger runs before or after the operation
+    pub trigger_type: Option<TriggerType>,
+    /// Operation that fires the trigger
+    pub trigger_operation: Option<TriggerOperation>,
+    /// Internal resource ID
+    #[serde(rename = "_rid")]
+    pub rid: Option<String>,
+    /// Last modification timestamp (Unix seconds)
+    #[serde(rename = "_ts")]
+    pub ts: Option<i64>,
+    /// Entity tag
+    #[serde(rename = "_etag")]
+    pub etag: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+string_enum! {
+    /// When a trigger runs
+    pub enum TriggerType {
+        /// Before the operation
+        Pre => "Pre",
+        /// After the operation
+        Post => "Post",
+    }
+}
+
+string_enum! {
+    /// Operation that fires a trigger
+    pub enum TriggerOperation {
+        /// Every operation
+        All => "All",
+        /// Document creation
+        Create => "Create",
+        /// Document update
+        Update => "Update",
+        /// Document deletion
+        Delete => "Delete",
+        /// Document replacement
+        Replace => "Replace",
+    }
+}
+
+/// SQL user-defined function information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDefinedFunction {
+    /// Function name
+    pub name: String,
+    /// Function ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Location
+    pub location: Option<String>,
+    /// Associated tags
+    pub tags: Option<HashMap<String, String>>,
+    /// User-defined function resource properties
+    pub resource: Option<UserDefinedFunctionResource>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl UserDefinedFunction {
+    /// Gets the JavaScript body
+    pub fn body(&self) -> Option<&str> {
+        self.resource.as_ref()?.body.as_deref()
+    }
+}
+
+/// User-defined function resource properties
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDefinedFunctionResource {
+    /// Function name
+    pub id: String,
+    /// JavaScript body
+    pub body: Option<String>,
+    /// Internal resource ID
+    #[serde(rename = "_rid")]
+    pub rid: Option<String>,
+    /// Last modification timestamp (Unix seconds)
+    #[serde(rename = "_ts")]
+    pub ts: Option<i64>,
+    /// Entity tag
+    #[serde(rename = "_etag")]
+    pub etag: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// MongoDB collection information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MongoCollection {
+    /// Collection name
+    pub name: String,
+    /// Collection ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Location
+    pub location: Option<String>,
+    /// Associated tags
+    pub tags: Option<HashMap<String, String>>,
+    /// Collection resource properties
+    pub resource: Option<MongoCollectionResource>,
+    /// Collection options (dedicated throughput)
+    pub options: Option<ResourceOptions>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl MongoCollection {
+    /// Gets the shard key, mapping each field to its sharding kind (e.g. `Hash`)
+    pub fn shard_key(&self) -> Option<&HashMap<String, String>> {
+        self.resource.as_ref()?.shard_key.as_ref()
+    }
+
+    /// Whether the collection is sharded
+    pub fn is_sharded(&self) -> bool {
+        self.shard_key().is_some_and(|shard_key| !shard_key.is_empty())
+    }
+
+    /// Gets the index definitions
+    pub fn indexes(&self) -> &[MongoIndex] {
+        self.resource.as_ref().and_then(|resource| resource.indexes.as_deref()).unwrap_or_default()
+    }
+
+    /// Finds the index on exactly the given keys, in order
+    pub fn index(&self, keys: &[&str]) -> Option<&MongoIndex> {
+        self.indexes().iter().find(|index| index.keys() == keys)
+    }
+
+    /// Gets the document time-to-live in seconds, from the TTL index if there is one
+    pub fn ttl(&self) -> Option<i64> {
+        self.indexes().iter().find_map(MongoIndex::expire_after_seconds)
+    }
+
+    /// Gets the analytical store time-to-live in seconds (`-1` keeps data indefinitely)
+    pub fn analytical_storage_ttl(&self) -> Option<i64> {
+        self.resource.as_ref()?.analytical_storage_ttl
+    }
+
+    /// Gets the manual throughput configured at creation, if any
+    pub fn throughput(&self) -> Option<i32> {
+        self.options.as_ref()?.throughput
+    }
+
+    /// Gets the autoscale settings configured at creation, if any
+    pub fn autoscale_settings(&self) -> Option<&AutoscaleSettings> {
+        self.options.as_ref()?.autoscale_settings.as_ref()
+    }
+}
+
+/// MongoDB collection resource properties
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MongoCollectionResource {
+    /// Collection name
+    pub id: String,
+    /// Shard key fields and their sharding kind
+    pub shard_key: Option<HashMap<String, String>>,
+    /// Index definitions
+    pub indexes: Option<Vec<MongoIndex>>,
+    /// Analytical store time-to-live in seconds
+    pub analytical_storage_ttl: Option<i64>,
+    /// Internal resource ID
+    #[serde(rename = "_rid")]
+    pub rid: Option<String>,
+    /// Last modification timestamp (Unix seconds)
+    #[serde(rename = "_ts")]
+    pub ts: Option<i64>,
+    /// Entity tag
+    #[serde(rename = "_etag")]
+    pub etag: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// MongoDB index definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MongoIndex {
+    /// Indexed fields
+    pub key: Option<MongoIndexKeys>,
+    /// Index options
+    pub options: Option<MongoIndexOptions>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl MongoIndex {
+    /// Gets the indexed fields, in order
+    pub fn keys(&self) -> &[String] {
+        self.key.as_ref().map(|key| key.keys.as_slice()).unwrap_or_default()
+    }
+
+    /// Whether the index enforces uniqueness
+    pub fn is_unique(&self) -> bool {
+        self.options.as_ref().and_then(|options| options.unique).unwrap_or(false)
+    }
+
+    /// Gets the expiry of a TTL index, in seconds
+    pub fn expire_after_seconds(&self) -> Option<i64> {
+        self.options.as_ref()?.expire_after_seconds
+    }
+
+    /// Whether the index is a compound index over several fields
+    pub fn is_compound(&self) -> bool {
+        self.keys().len() > 1
+    }
+}
+
+/// Fields of a MongoDB index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MongoIndexKeys {
+    /// Field names
+    pub keys: Vec<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// MongoDB index options
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MongoIndexOptions {
+    /// Whether the index enforces uniqueness
+    pub unique: Option<bool>,
+    /// Document expiry in seconds (TTL index)
+    pub expire_after_seconds: Option<i64>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Cassandra table information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CassandraTable {
+    /// Table name
+    pub name: String,
+    /// Table ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Location
+    pub location: Option<String>,
+    /// Associated tags
+    pub tags: Option<HashMap<String, String>>,
+    /// Table resource properties
+    pub resource: Option<CassandraTableResource>,
+    /// Table options (dedicated throughput)
+    pub options: Option<ResourceOptions>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl CassandraTable {
+    /// Gets the table schema
+    pub fn schema(&self) -> Option<&CassandraSchema> {
+        self.resource.as_ref()?.schema.as_ref()
+    }
+
+    /// Gets the columns
+    pub fn columns(&self) -> &[CassandraColumn] {
+        self.schema().map(|schema| schema.columns.as_slice()).unwrap_or_default()
+    }
+
+    /// Gets the partition key columns, in key order
+    pub fn partition_keys(&self) -> &[CassandraPartitionKey] {
+        self.schema().and_then(|schema| schema.partition_keys.as_deref()).unwrap_or_default()
+    }
+
+    /// Gets the clustering key columns, in key order
+    pub fn cluster_keys(&self) -> &[CassandraClusterKey] {
+        self.schema().and_then(|schema| schema.cluster_keys.as_deref()).unwrap_or_default()
+    }
+
+    /// Gets the default time-to-live in seconds (`0` disables expiry)
+    pub fn default_ttl(&self) -> Option<i64> {
+        self.resource.as_ref()?.default_ttl
+    }
+
+    /// Gets the analytical store time-to-live in seconds (`-1` keeps data indefinitely)
+    pub fn analytical_storage_ttl(&self) -> Option<i64> {
+        self.resource.as_ref()?.analytical_storage_ttl
+    }
+
+    /// Gets the manual throughput configured at creation, if any
+    pub fn throughput(&self) -> Option<i32> {
+        self.options.as_ref()?.throughput
+    }
+
+    /// Gets the autoscale settings configured at creation, if any
+    pub fn autoscale_settings(&self) -> Option<&AutoscaleSettings> {
+        self.options.as_ref()?.autoscale_settings.as_ref()
+    }
+}
+
+/// Cassandra table resource properties
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CassandraTableResource {
+    /// Table name
+    pub id: String,
+    /// Table schema
+    pub schema: Option<CassandraSchema>,
+    /// Default time-to-live in seconds
+    pub default_ttl: Option<i64>,
+    /// Analytical store time-to-live in seconds
+    pub analytical_storage_ttl: Option<i64>,
+    /// Internal resource ID
+    #[serde(rename = "_rid")]
+    pub rid: Option<String>,
+    /// Last modification timestamp (Unix seconds)
+    #[serde(rename = "_ts")]
+    pub ts: Option<i64>,
+    /// Entity tag
+    #[serde(rename = "_etag")]
+    pub etag: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Cassandra table schema
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CassandraSchema {
+    /// Column definitions
+    pub columns: Vec<CassandraColumn>,
+    /// Partition key columns
+    pub partition_keys: Option<Vec<CassandraPartitionKey>>,
+    /// Clustering key columns
+    pub cluster_keys: Option<Vec<CassandraClusterKey>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl CassandraSchema {
+    /// Finds a column by name
+    pub fn column(&self, name: &str) -> Option<&CassandraColumn> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+}
+
+/// Cassandra column
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CassandraColumn {
+    /// Column name
+    pub name: String,
+    /// CQL type (e.g. `uuid`, `text`, `map<text, int>`)
+    #[serde(rename = "type")]
+    pub column_type: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Cassandra partition key column
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CassandraPartitionKey {
+    /// Column name
+    pub name: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Cassandra clustering key column
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CassandraClusterKey {
+    /// Column name
+    pub name: String,
+    /// Sort order (`Asc` or `Desc`)
+    pub order_by: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Table API table information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CosmosTable {
+    /// Table name
+    pub name: String,
+    /// Table ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Location
+    pub location: Option<String>,
+    /// Associated tags
+    pub tags: Option<HashMap<String, String>>,
+    /// Table resource properties
+    pub resource: Option<TableResource>,
+    /// Table options (dedicated throughput)
+    pub options: Option<ResourceOptions>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl CosmosTable {
+    /// Gets the manual throughput configured at creation, if any
+    pub fn throughput(&self) -> Option<i32> {
+        self.options.as_ref()?.throughput
+    }
+
+    /// Gets the autoscale settings configured at creation, if any
+    pub fn autoscale_settings(&self) -> Option<&AutoscaleSettings> {
+        self.options.as_ref()?.autoscale_settings.as_ref()
+    }
+}
+
+/// Table API table resource properties
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableResource {
+    /// Table name
+    pub id: String,
+    /// Internal resource ID
+    #[serde(rename = "_rid")]
+    pub rid: Option<String>,
+    /// Last modification timestamp (Unix seconds)
+    #[serde(rename = "_ts")]
+    pub ts: Option<i64>,
+    /// Entity tag
+    #[serde(rename = "_etag")]
+    pub etag: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Partition key
+///
+/// Hierarchical (`MultiHash`) keys list one path per level, from the top level down.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartitionKey {
+    /// Partition key paths
+    pub paths: Vec<String>,
+    /// Partition key type
+    pub kind: PartitionKeyKind,
+    /// Partition key version (`2` supports keys larger than 100 bytes)
+    pub version: Option<i32>,
+    /// Whether the key is system-generated (containers migrated from non-partitioned)
+    pub system_key: Option<bool>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl PartitionKey {
+    /// Maximum number of levels in a hierarchical partition key
+    pub const MAX_HIERARCHY_DEPTH: usize = 3;
+
+    /// Whether the container uses hierarchical partition keys (subpartitioning)
+    pub fn is_hierarchical(&self) -> bool {
+        self.kind == PartitionKeyKind::MultiHash
+    }
+
+    /// Number of levels in the key (1 for non-hierarchical keys)
+    pub fn depth(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Describes each level of the key, from the top level down
+    pub fn levels(&self) -> Vec<PartitionKeyLevel<'_>> {
+        self.paths
+            .iter()
+            .enumerate()
+            .map(|(index, path)| PartitionKeyLevel {
+                level: index + 1,
+                path,
+                segments: path_segments(path),
+            })
+            .collect()
+    }
+
+    /// Extracts the value of each level from a document, `None` where the path is absent
+    pub fn values<'a>(&self, document: &'a Value) -> Vec<Option<&'a Value>> {
+        self.levels().iter().map(|level| level.value(document)).collect()
+    }
+
+    /// Checks a document against the key, returning every violation found
+    ///
+    /// An empty result means the document provides a usable value at every level.
+    /// Values must be strings, numbers, booleans or `null`.
+    pub fn validate_document(&self, document: &Value) -> Vec<PartitionKeyViolation> {
+        self.levels()
+            .iter()
+            .filter_map(|level| match level.value(document) {
+                None => Some(PartitionKeyViolation::Missing { path: level.path.to_string() }),
+                Some(Value::Object(_) | Value::Array(_)) => {
+                    Some(PartitionKeyViolation::InvalidType { path: level.path.to_string() })
+                }
+                Some(_) => None,
+            })
+            .collect()
+    }
+}
+
+string_enum! {
+    /// Partition key type
+    pub enum PartitionKeyKind {
+        /// Hash partitioning on a single path
+        Hash => "Hash",
+        /// Range partitioning (legacy)
+        Range => "Range",
+        /// Hierarchical partitioning on up to three paths
+        MultiHash => "MultiHash",
+    }
+}
+
+/// A single level of a partition key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionKeyLevel<'a> {
+    /// Level number, starting at 1 for the top level
+    pub level: usize,
+    /// Partition key path (e.g. `/address/zipCode`)
+    pub path: &'a str,
+    /// Property names along the path (e.g. `["address", "zipCode"]`)
+    pub segments: Vec<String>,
+}
+
+impl PartitionKeyLevel<'_> {
+    /// Looks up this level's value in a document
+    pub fn value<'d>(&self, document: &'d Value) -> Option<&'d Value> {
+        self.segments
+            .iter()
+            .try_fold(document, |value, segment| value.get(segment.as_str()))
+    }
+}
+
+/// A document that does not satisfy a partition key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartitionKeyViolation {
+    /// The document has no value at the path
+    Missing {
+        /// Partition key path
+        path: String,
+    },
+    /// The value at the path is an object or array
+    InvalidType {
+        /// Partition key path
+        path: String,
+    },
+}
+
+/// Splits a partition key path into property names, unquoting `/"quoted"` segments
+fn path_segments(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.trim_matches('"').to_string())
+        .collect()
+}
+
+/// Indexing policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexingPolicy {
+    /// Whether automatic indexing is enabled
+    pub automatic: Option<bool>,
+    /// Indexing mode
+    pub indexing_mode: Option<String>,
+    /// Included paths
+    pub included_paths: Option<Vec<IndexPath>>,
+    /// Excluded paths
+    pub excluded_paths: Option<Vec<IndexPath>>,
+    /// Composite indexes, each an ordered list of paths
+    pub composite_indexes: Option<Vec<Vec<CompositePath>>>,
+    /// Spatial indexes
+    pub spatial_indexes: Option<Vec<SpatialIndex>>,
+    /// Vector indexes
+    pub vector_indexes: Option<Vec<VectorIndex>>,
+    /// Full-text indexes
+    pub full_text_indexes: Option<Vec<FullTextIndexPath>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Index path
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPath {
+    /// Path
+    pub path: String,
+    /// Per-path index entries (legacy indexing policies)
+    pub indexes: Option<Vec<IndexEntry>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Per-path index entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexEntry {
+    /// Index kind (`Hash`, `Range` or `Spatial`)
+    pub kind: Option<String>,
+    /// Indexed data type (`String`, `Number`, `Point`, ...)
+    pub data_type: Option<String>,
+    /// Index precision (`-1` for maximum)
+    pub precision: Option<i32>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Composite index path
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositePath {
+    /// Path
+    pub path: String,
+    /// Sort order (`ascending` or `descending`)
+    pub order: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Spatial index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpatialIndex {
+    /// Path
+    pub path: String,
+    /// Indexed spatial types (`Point`, `LineString`, `Polygon`, `MultiPolygon`)
+    pub types: Vec<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Vector index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorIndex {
+    /// Path of the vector property
+    pub path: String,
+    /// Index type (`flat`, `quantizedFlat` or `diskANN`)
+    #[serde(rename = "type")]
+    pub index_type: String,
+    /// Bytes used for product quantization
+    pub quantization_byte_size: Option<u32>,
+    /// Number of vectors searched at query time (`diskANN` only)
+    pub indexing_search_list_size: Option<u32>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Full-text index path
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullTextIndexPath {
+    /// Path
+    pub path: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Cosmos Account access keys
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CosmosKeys {
+    /// Primary master key (absent when only read-only keys were requested)
+    pub primary_master_key: Option<String>,
+    /// Secondary master key (absent when only read-only keys were requested)
+    pub secondary_master_key: Option<String>,
     /// Primary readonly master key
     pub primary_readonly_master_key: String,
     /// Secondary readonly master key
     pub secondary_readonly_master_key: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Cosmos Account connection strings
@@ -539,6 +2544,9 @@ pub struct CosmosKeys {
 pub struct CosmosConnectionStrings {
     /// Connection strings
     pub connection_strings: Vec<ConnectionString>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
 }
 
 /// Individual connection string
@@ -549,4 +2557,717 @@ pub struct ConnectionString {
     pub description: String,
     /// Connection string
     pub connection_string: String,
-} 
\ No newline at end of file
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+string_enum! {
+    /// Whether a data-plane role is predefined or account specific
+    pub enum RoleDefinitionType {
+        /// Built-in role (Data Reader, Data Contributor, ...)
+        BuiltInRole => "BuiltInRole",
+        /// Custom role
+        CustomRole => "CustomRole",
+    }
+}
+
+/// Data action allowing point reads of items
+const READ_ITEMS_ACTION: &str = "Microsoft.DocumentDB/databaseAccounts/sqlDatabases/containers/items/read";
+
+/// Data actions allowing items to be written
+const WRITE_ITEMS_ACTIONS: &[&str] = &[
+    "Microsoft.DocumentDB/databaseAccounts/sqlDatabases/containers/items/create",
+    "Microsoft.DocumentDB/databaseAccounts/sqlDatabases/containers/items/upsert",
+    "Microsoft.DocumentDB/databaseAccounts/sqlDatabases/containers/items/replace",
+    "Microsoft.DocumentDB/databaseAccounts/sqlDatabases/containers/items/delete",
+];
+
+/// SQL data-plane role definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqlRoleDefinition {
+    /// Role definition GUID
+    pub name: String,
+    /// Role definition ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Role display name
+    pub role_name: Option<String>,
+    /// Built-in or custom (reported by `az` as `typePropertiesType`)
+    #[serde(rename = "typePropertiesType")]
+    pub role_type: Option<RoleDefinitionType>,
+    /// Scopes the role can be assigned at
+    pub assignable_scopes: Option<Vec<String>>,
+    /// Granted data actions
+    pub permissions: Option<Vec<RolePermission>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl SqlRoleDefinition {
+    /// Gets the granted data actions
+    pub fn permissions(&self) -> &[RolePermission] {
+        self.permissions.as_deref().unwrap_or_default()
+    }
+
+    /// Gets the scopes the role can be assigned at
+    pub fn assignable_scopes(&self) -> &[String] {
+        self.assignable_scopes.as_deref().unwrap_or_default()
+    }
+
+    /// Whether this is a built-in role
+    pub fn is_built_in(&self) -> bool {
+        self.role_type == Some(RoleDefinitionType::BuiltInRole)
+    }
+
+    /// Whether the role grants a data action (e.g. `.../sqlDatabases/containers/items/read`)
+    pub fn allows(&self, data_action: &str) -> bool {
+        self.permissions().iter().any(|permission| permission.allows(data_action))
+    }
+
+    /// Whether the role allows reading items
+    pub fn can_read(&self) -> bool {
+        self.allows(READ_ITEMS_ACTION)
+    }
+
+    /// Whether the role allows creating, upserting, replacing or deleting items
+    pub fn can_write(&self) -> bool {
+        WRITE_ITEMS_ACTIONS.iter().any(|action| self.allows(action))
+    }
+}
+
+/// Data actions granted by a role definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RolePermission {
+    /// Allowed data actions, possibly ending in a `*` wildcard
+    pub data_actions: Option<Vec<String>>,
+    /// Data actions excluded from `data_actions`
+    pub not_data_actions: Option<Vec<String>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl RolePermission {
+    /// Whether the permission grants a data action, taking wildcards and exclusions into account
+    pub fn allows(&self, data_action: &str) -> bool {
+        let matches = |patterns: &Option<Vec<String>>| {
+            patterns.iter().flatten().any(|pattern| data_action_matches(pattern, data_action))
+        };
+        matches(&self.data_actions) && !matches(&self.not_data_actions)
+    }
+}
+
+/// Matches a data action against a pattern such as `.../containers/items/*` (case-insensitive)
+fn data_action_matches(pattern: &str, data_action: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => data_action.len() >= prefix.len()
+            && data_action.is_char_boundary(prefix.len())
+            && data_action[..prefix.len()].eq_ignore_ascii_case(prefix),
+        None => pattern.eq_ignore_ascii_case(data_action),
+    }
+}
+
+/// SQL data-plane role assignment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqlRoleAssignment {
+    /// Role assignment GUID
+    pub name: String,
+    /// Role assignment ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Object ID of the assigned Microsoft Entra principal
+    pub principal_id: String,
+    /// ID of the assigned role definition
+    pub role_definition_id: AzureResourceId,
+    /// Scope of the assignment (the account, `.../dbs/{db}` or `.../dbs/{db}/colls/{container}`)
+    pub scope: String,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl SqlRoleAssignment {
+    /// Whether the assignment applies to a scope, directly or through a parent scope
+    ///
+    /// Scopes can be full (`/subscriptions/.../databaseAccounts/{account}/dbs/{db}`) or
+    /// relative to the account (`/dbs/{db}/colls/{container}`, or `/` for the account).
+    pub fn covers(&self, scope: &str) -> bool {
+        let assigned = relative_data_scope(&self.scope);
+        let target = relative_data_scope(scope);
+        assigned.is_empty()
+            || target == assigned
+            || target.strip_prefix(assigned).is_some_and(|rest| rest.starts_with('/'))
+    }
+
+    /// Finds the assigned role definition
+    pub fn resolve<'a>(&self, definitions: &'a [SqlRoleDefinition]) -> Option<&'a SqlRoleDefinition> {
+        definitions.iter().find(|definition| definition.id == self.role_definition_id)
+    }
+}
+
+/// Strips the account part of a data-plane scope (`.../databaseAccounts/x/dbs/db` → `/dbs/db`)
+fn relative_data_scope(scope: &str) -> &str {
+    const ACCOUNT_SEGMENT: &str = "/databaseaccounts/";
+    let relative = match scope.to_ascii_lowercase().find(ACCOUNT_SEGMENT) {
+        Some(start) => {
+            let rest = &scope[start + ACCOUNT_SEGMENT.len()..];
+            rest.find('/').map_or("", |end| &rest[end..])
+        }
+        None => scope,
+    };
+    relative.trim_end_matches('/')
+}
+
+/// Data-plane access of a principal to a scope, resolved from SQL role assignments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataPlaneAccess {
+    /// Object ID of the Microsoft Entra principal
+    pub principal_id: String,
+    /// Whether the principal can read items
+    pub can_read: bool,
+    /// Whether the principal can create, upsert, replace or delete items
+    pub can_write: bool,
+    /// Names of the roles granting access
+    pub roles: Vec<String>,
+}
+
+impl DataPlaneAccess {
+    /// Resolves which principals can read or write items in a scope
+    ///
+    /// Assignments at the scope or any parent scope are combined per principal; principals
+    /// whose roles grant neither read nor write access are left out. Assignments of roles
+    /// missing from `definitions` are ignored.
+    pub fn resolve(definitions: &[SqlRoleDefinition], assignments: &[SqlRoleAssignment], scope: &str) -> Vec<DataPlaneAccess> {
+        let mut access: Vec<DataPlaneAccess> = Vec::new();
+
+        for assignment in assignments.iter().filter(|assignment| assignment.covers(scope)) {
+            let Some(definition) = assignment.resolve(definitions) else {
+                continue;
+            };
+            let (can_read, can_write) = (definition.can_read(), definition.can_write());
+            if !can_read && !can_write {
+                continue;
+            }
+
+            let role = definition.role_name.clone().unwrap_or_else(|| definition.name.clone());
+            match access.iter_mut().find(|entry| entry.principal_id.eq_ignore_ascii_case(&assignment.principal_id)) {
+                Some(entry) => {
+                    entry.can_read |= can_read;
+                    entry.can_write |= can_write;
+                    if !entry.roles.contains(&role) {
+                        entry.roles.push(role);
+                    }
+                }
+                None => access.push(DataPlaneAccess {
+                    principal_id: assignment.principal_id.clone(),
+                    can_read,
+                    can_write,
+                    roles: vec![role],
+                }),
+            }
+        }
+
+        access
+    }
+}
+
+/// MongoDB role definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MongoRoleDefinition {
+    /// Role definition ID (`{database}.{role}`)
+    pub name: String,
+    /// Role definition resource ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// Role name
+    pub role_name: Option<String>,
+    /// Built-in or custom (reported by `az` as `typePropertiesType`)
+    #[serde(rename = "typePropertiesType")]
+    pub role_type: Option<RoleDefinitionType>,
+    /// Database the role is defined in
+    pub database_name: Option<String>,
+    /// Granted privileges
+    pub privileges: Option<Vec<MongoPrivilege>>,
+    /// Inherited roles
+    pub roles: Option<Vec<MongoRole>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl MongoRoleDefinition {
+    /// Gets the granted privileges
+    pub fn privileges(&self) -> &[MongoPrivilege] {
+        self.privileges.as_deref().unwrap_or_default()
+    }
+
+    /// Gets the inherited roles
+    pub fn roles(&self) -> &[MongoRole] {
+        self.roles.as_deref().unwrap_or_default()
+    }
+
+    /// Whether a privilege of the role grants an action (e.g. `find`) on a collection
+    ///
+    /// Only the role's own privileges are checked, not those of inherited roles.
+    pub fn allows(&self, action: &str, database: &str, collection: &str) -> bool {
+        self.privileges().iter().any(|privilege| {
+            privilege.applies_to(database, collection)
+                && privilege.actions.iter().flatten().any(|granted| granted.eq_ignore_ascii_case(action))
+        })
+    }
+}
+
+/// Actions granted on a MongoDB resource
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MongoPrivilege {
+    /// Database and collection the privilege applies to
+    pub resource: Option<MongoPrivilegeResource>,
+    /// Granted actions (`find`, `insert`, `update`, `remove`, ...)
+    pub actions: Option<Vec<String>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl MongoPrivilege {
+    /// Whether the privilege applies to a collection (an empty or missing name matches any)
+    pub fn applies_to(&self, database: &str, collection: &str) -> bool {
+        let Some(resource) = &self.resource else {
+            return false;
+        };
+        let matches = |pattern: &Option<String>, name: &str| pattern.as_deref().is_none_or(|pattern| pattern.is_empty() || pattern == name);
+        matches(&resource.db, database) && matches(&resource.collection, collection)
+    }
+}
+
+/// Resource a MongoDB privilege applies to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MongoPrivilegeResource {
+    /// Database name
+    pub db: Option<String>,
+    /// Collection name
+    pub collection: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Role granted to a MongoDB role or user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MongoRole {
+    /// Role name
+    pub role: String,
+    /// Database the role is defined in
+    pub db: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// MongoDB user definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MongoUserDefinition {
+    /// User definition ID (`{database}.{user}`)
+    pub name: String,
+    /// User definition resource ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Resource group
+    pub resource_group: Option<String>,
+    /// User name
+    pub user_name: Option<String>,
+    /// Database the user is defined in
+    pub database_name: Option<String>,
+    /// Authentication mechanisms (e.g. `SCRAM-SHA-256`)
+    pub mechanisms: Option<String>,
+    /// Custom data
+    pub custom_data: Option<String>,
+    /// Granted roles
+    pub roles: Option<Vec<MongoRole>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl MongoUserDefinition {
+    /// Gets the granted roles
+    pub fn roles(&self) -> &[MongoRole] {
+        self.roles.as_deref().unwrap_or_default()
+    }
+
+    /// Whether the user is granted a role defined in a database
+    pub fn has_role(&self, database: &str, role: &str) -> bool {
+        self.roles()
+            .iter()
+            .any(|granted| granted.role == role && granted.db.as_deref().is_none_or(|db| db == database))
+    }
+}
+
+/// Parses a timestamp as reported by the restore APIs
+///
+/// Accepts RFC 3339 (`2024-03-15T12:00:00+00:00`), naive ISO 8601 in UTC, and the
+/// US format `retrieve-latest-backup-time` uses (`3/15/2024 12:00:00 PM`).
+fn parse_restore_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    let timestamp = timestamp.trim();
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(timestamp) {
+        return Some(parsed.with_timezone(&Utc));
+    }
+    ["%Y-%m-%dT%H:%M:%S%.f", "%m/%d/%Y %I:%M:%S %p"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(timestamp, format).ok())
+        .map(|naive| naive.and_utc())
+}
+
+/// Restorable (live or deleted) Cosmos DB account with continuous backup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestorableDatabaseAccount {
+    /// Account instance ID
+    pub name: String,
+    /// Restorable account ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Location
+    pub location: Option<String>,
+    /// Account name
+    pub account_name: Option<String>,
+    /// API type (`Sql`, `MongoDB`, `Cassandra`, `Gremlin`, `Table`, ...)
+    pub api_type: Option<String>,
+    /// Creation time
+    pub creation_time: Option<String>,
+    /// Deletion time, for deleted accounts
+    pub deletion_time: Option<String>,
+    /// Earliest time the account can be restored to
+    pub oldest_restorable_time: Option<String>,
+    /// Regions the account can be restored from
+    pub restorable_locations: Option<Vec<RestorableLocation>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl RestorableDatabaseAccount {
+    /// Gets the account instance ID, as expected by `--instance-id`
+    pub fn instance_id(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the creation time
+    pub fn created(&self) -> Option<DateTime<Utc>> {
+        parse_restore_timestamp(self.creation_time.as_deref()?)
+    }
+
+    /// Gets the deletion time, for deleted accounts
+    pub fn deleted(&self) -> Option<DateTime<Utc>> {
+        parse_restore_timestamp(self.deletion_time.as_deref()?)
+    }
+
+    /// Whether the account has been deleted
+    pub fn is_deleted(&self) -> bool {
+        self.deletion_time.is_some()
+    }
+
+    /// Gets the earliest time the account can be restored to (its creation time if not reported)
+    pub fn earliest_restorable_time(&self) -> Option<DateTime<Utc>> {
+        self.oldest_restorable_time
+            .as_deref()
+            .and_then(parse_restore_timestamp)
+            .or_else(|| self.created())
+    }
+
+    /// Gets the regions the account can be restored from
+    pub fn restorable_locations(&self) -> &[RestorableLocation] {
+        self.restorable_locations.as_deref().unwrap_or_default()
+    }
+}
+
+/// Region a restorable account can be restored from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestorableLocation {
+    /// Region name
+    pub location_name: Option<String>,
+    /// Instance ID of the account in this region
+    pub regional_database_account_instance_id: Option<String>,
+    /// Time the region was added
+    pub creation_time: Option<String>,
+    /// Time the region was removed
+    pub deletion_time: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+string_enum! {
+    /// Operation recorded in the restorable event feed
+    pub enum RestorableOperationType {
+        /// Resource created
+        Create => "Create",
+        /// Resource settings replaced
+        Replace => "Replace",
+        /// Resource deleted
+        Delete => "Delete",
+        /// Resource recreated after a deletion
+        Recreate => "Recreate",
+        /// Operation performed by the service
+        SystemOperation => "SystemOperation",
+    }
+}
+
+/// Restorable SQL database event (`az cosmosdb sql restorable-database list`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestorableSqlDatabase {
+    /// Event ID
+    pub name: String,
+    /// Restorable database ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Event properties
+    pub resource: Option<RestorableEvent>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl RestorableSqlDatabase {
+    /// Gets the database name
+    pub fn database_name(&self) -> Option<&str> {
+        self.resource.as_ref()?.owner_id.as_deref()
+    }
+
+    /// Gets the database resource ID (`_rid`), as expected by `--database-rid`
+    pub fn database_rid(&self) -> Option<&str> {
+        self.resource.as_ref()?.owner_resource_id.as_deref()
+    }
+
+    /// Gets the recorded operation
+    pub fn operation_type(&self) -> Option<&RestorableOperationType> {
+        self.resource.as_ref()?.operation_type.as_ref()
+    }
+
+    /// Gets the time of the event
+    pub fn event_time(&self) -> Option<DateTime<Utc>> {
+        self.resource.as_ref()?.event_time()
+    }
+}
+
+/// Restorable SQL container event (`az cosmosdb sql restorable-container list`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestorableSqlContainer {
+    /// Event ID
+    pub name: String,
+    /// Restorable container ID
+    pub id: AzureResourceId,
+    /// Resource type
+    #[serde(rename = "type")]
+    pub resource_type: Option<String>,
+    /// Event properties
+    pub resource: Option<RestorableEvent>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl RestorableSqlContainer {
+    /// Gets the container name
+    pub fn container_name(&self) -> Option<&str> {
+        self.resource.as_ref()?.owner_id.as_deref()
+    }
+
+    /// Gets the container resource ID (`_rid`)
+    pub fn container_rid(&self) -> Option<&str> {
+        self.resource.as_ref()?.owner_resource_id.as_deref()
+    }
+
+    /// Gets the recorded operation
+    pub fn operation_type(&self) -> Option<&RestorableOperationType> {
+        self.resource.as_ref()?.operation_type.as_ref()
+    }
+
+    /// Gets the time of the event
+    pub fn event_time(&self) -> Option<DateTime<Utc>> {
+        self.resource.as_ref()?.event_time()
+    }
+}
+
+/// Properties of a restorable database or container event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestorableEvent {
+    /// Event resource ID
+    #[serde(rename = "_rid")]
+    pub rid: Option<String>,
+    /// Recorded operation
+    pub operation_type: Option<RestorableOperationType>,
+    /// Time of the event
+    pub event_timestamp: Option<String>,
+    /// Name of the database or container
+    pub owner_id: Option<String>,
+    /// Resource ID (`_rid`) of the database or container
+    pub owner_resource_id: Option<String>,
+    /// Whether the resource can be undeleted
+    pub can_undelete: Option<String>,
+    /// Why the resource can or cannot be undeleted
+    pub can_undelete_reason: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl RestorableEvent {
+    /// Gets the time of the event
+    pub fn event_time(&self) -> Option<DateTime<Utc>> {
+        parse_restore_timestamp(self.event_timestamp.as_deref()?)
+    }
+}
+
+/// Database and containers restorable at a point in time (`az cosmosdb sql restorable-resource list`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestorableSqlResource {
+    /// Database name
+    pub database_name: String,
+    /// Names of the restorable containers
+    pub collection_names: Option<Vec<String>>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl RestorableSqlResource {
+    /// Gets the names of the restorable containers
+    pub fn container_names(&self) -> &[String] {
+        self.collection_names.as_deref().unwrap_or_default()
+    }
+}
+
+/// Latest backup of a container (`az cosmosdb sql retrieve-latest-backup-time`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupInformation {
+    /// Continuous backup information
+    pub continuous_backup_information: Option<ContinuousBackupInformation>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+impl BackupInformation {
+    /// Gets the latest time the container can be restored to
+    pub fn latest_restorable_time(&self) -> Option<DateTime<Utc>> {
+        parse_restore_timestamp(self.continuous_backup_information.as_ref()?.latest_restorable_timestamp.as_deref()?)
+    }
+}
+
+/// Continuous backup information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinuousBackupInformation {
+    /// Latest restorable timestamp, as reported by Azure (`3/15/2024 12:00:00 PM`, UTC)
+    pub latest_restorable_timestamp: Option<String>,
+    /// Properties not modeled by this crate
+    #[serde(flatten, with = "crate::serialization::extra")]
+    pub extra: Map<String, Value>,
+}
+
+/// Range of timestamps a resource can be restored to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestoreWindow {
+    /// Earliest restorable timestamp
+    pub earliest: DateTime<Utc>,
+    /// Latest restorable timestamp
+    pub latest: DateTime<Utc>,
+}
+
+impl RestoreWindow {
+    /// Computes the window of a container from its account and container events
+    ///
+    /// The window opens at the later of the account's earliest restorable time and the
+    /// container's most recent creation, and closes at `latest` (the latest backup time).
+    pub fn for_container(
+        account: &RestorableDatabaseAccount,
+        events: &[RestorableSqlContainer],
+        container_name: &str,
+        latest: DateTime<Utc>,
+    ) -> Option<Self> {
+        let created = events
+            .iter()
+            .filter(|event| event.container_name() == Some(container_name))
+            .filter(|event| matches!(event.operation_type(), Some(RestorableOperationType::Create | RestorableOperationType::Recreate)))
+            .filter_map(RestorableSqlContainer::event_time)
+            .max();
+        let earliest = account.earliest_restorable_time().into_iter().chain(created).max()?;
+        (earliest <= latest).then_some(Self { earliest, latest })
+    }
+
+    /// Whether a timestamp can be restored to
+    pub fn contains(&self, timestamp: DateTime<Utc>) -> bool {
+        self.earliest <= timestamp && timestamp <= self.latest
+    }
+
+    /// Gets the length of the window
+    pub fn duration(&self) -> chrono::Duration {
+        self.latest - self.earliest
+    }
+}
+
+impl_system_properties!(
+    CosmosDatabase, CosmosContainer, StoredProcedure, Trigger, UserDefinedFunction, MongoCollection,
+    CassandraTable, CosmosTable,
+);
+
+impl_extra_fields!(
+    Subscription, ManagedByTenant, SubscriptionUser, Tenant, Location, LocationMetadata,
+    PairedRegion, AvailabilityZoneMapping, ResourceGroup, ResourceGroupProperties, GenericResource,
+    ResourceSku, CosmosAccount,
+    AnalyticalStorageConfiguration, ApiProperties, Capability, BackupPolicy,
+    PeriodicBackupProperties, ContinuousBackupProperties, FailoverPolicy, AccountIdentity, IpRule,
+    KeysMetadata, KeyMetadata, PrivateEndpointConnection, PrivateEndpoint,
+    PrivateLinkServiceConnectionState, RestoreParameters, SystemData, VirtualNetworkRule,
+    CapacitySettings, ConsistencyPolicy, CorsPolicy, AccountLocation, CosmosDatabase,
+    DatabaseResource, ResourceOptions, ThroughputSettings, ThroughputSettingsResource, AutoscaleSettings,
+    AutoUpgradePolicy, ThroughputPolicy, CosmosContainer,
+    ContainerResource, UniqueKeyPolicy, UniqueKey, ConflictResolutionPolicy, ComputedProperty,
+    VectorEmbeddingPolicy, VectorEmbedding, FullTextPolicy, FullTextPath, ClientEncryptionPolicy,
+    ClientEncryptionIncludedPath, StoredProcedure, StoredProcedureResource, Trigger, TriggerResource,
+    UserDefinedFunction, UserDefinedFunctionResource, MongoCollection, MongoCollectionResource, MongoIndex,
+    MongoIndexKeys, MongoIndexOptions, CassandraTable,
+    CassandraTableResource, CassandraSchema, CassandraColumn, CassandraPartitionKey,
+    CassandraClusterKey, CosmosTable, TableResource, PartitionKey,
+    IndexingPolicy, IndexPath, IndexEntry, CompositePath, SpatialIndex, VectorIndex,
+    FullTextIndexPath, CosmosKeys, CosmosConnectionStrings, ConnectionString, SqlRoleDefinition,
+    RolePermission, SqlRoleAssignment, MongoRoleDefinition, MongoPrivilege, MongoPrivilegeResource,
+    MongoRole, MongoUserDefinition, RestorableDatabaseAccount, RestorableLocation, RestorableSqlDatabase,
+    RestorableSqlContainer, RestorableEvent, RestorableSqlResource, BackupInformation,
+    ContinuousBackupInformation,
+);
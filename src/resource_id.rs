@@ -0,0 +1,267 @@
+//! Azure Resource Manager resource IDs
+//!
+//! [`AzureResourceId`] parses IDs such as
+//! `/subscriptions/{sub}/resourceGroups/{rg}/providers/Microsoft.DocumentDB/databaseAccounts/{account}`
+//! into their subscription, resource group, provider and type/name segments, and builds
+//! child IDs without string concatenation.
+//!
+//! ```
+//! use rust_az_wrapper::AzureResourceId;
+//!
+//! let account: AzureResourceId = "/subscriptions/0000/resourceGroups/cosmos-rg/providers/Microsoft.DocumentDB/databaseAccounts/contoso"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(account.resource_group(), Some("cosmos-rg"));
+//! assert_eq!(account.resource_type().as_deref(), Some("Microsoft.DocumentDB/databaseAccounts"));
+//!
+//! let database = account.child("sqlDatabases", "appdb");
+//! assert_eq!(database.parent(), Some(account));
+//! ```
+
+use crate::error::{AzureError, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+const SUBSCRIPTIONS: &str = "subscriptions";
+const RESOURCE_GROUPS: &str = "resourceGroups";
+const PROVIDERS: &str = "providers";
+
+/// Provider namespace of Cosmos DB resources
+pub const COSMOS_DB_PROVIDER: &str = "Microsoft.DocumentDB";
+
+/// A parsed Azure Resource Manager resource ID
+///
+/// Comparison and hashing ignore case, as ARM does; [`Display`](fmt::Display) keeps the
+/// original casing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AzureResourceId {
+    /// Key/value segment pairs, e.g. `("resourceGroups", "cosmos-rg")`
+    segments: Vec<(String, String)>,
+}
+
+impl AzureResourceId {
+    /// Parses and validates a resource ID
+    pub fn parse(id: &str) -> Result<Self> {
+        let invalid = |reason: &str| AzureError::InvalidResourceId {
+            id: id.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let path = id.strip_prefix('/').ok_or_else(|| invalid("must start with '/'"))?;
+        let parts: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+        if parts.iter().any(|part| part.is_empty()) {
+            return Err(invalid("contains an empty segment"));
+        }
+        if !parts.len().is_multiple_of(2) {
+            return Err(invalid("segments must come in type/name pairs"));
+        }
+
+        let segments: Vec<(String, String)> = parts
+            .chunks(2)
+            .map(|pair| (pair[0].to_string(), pair[1].to_string()))
+            .collect();
+
+        let first = &segments[0].0;
+        if !first.eq_ignore_ascii_case(SUBSCRIPTIONS) && !first.eq_ignore_ascii_case(PROVIDERS) {
+            return Err(invalid("must start with /subscriptions or /providers"));
+        }
+        if let Some(index) = segments.iter().position(|(key, _)| key.eq_ignore_ascii_case(RESOURCE_GROUPS))
+            && (index != 1 || !first.eq_ignore_ascii_case(SUBSCRIPTIONS))
+        {
+            return Err(invalid("resourceGroups must directly follow the subscription"));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Creates the ID of a subscription
+    pub fn subscription(subscription_id: impl Into<String>) -> Self {
+        Self {
+            segments: vec![(SUBSCRIPTIONS.to_string(), subscription_id.into())],
+        }
+    }
+
+    /// Creates the ID of a resource group
+    pub fn resource_group_id(subscription_id: impl Into<String>, resource_group: impl Into<String>) -> Self {
+        Self::subscription(subscription_id).child(RESOURCE_GROUPS, resource_group)
+    }
+
+    /// Creates the ID of a Cosmos DB account
+    pub fn cosmos_account(
+        subscription_id: impl Into<String>,
+        resource_group: impl Into<String>,
+        account_name: impl Into<String>,
+    ) -> Self {
+        Self::resource_group_id(subscription_id, resource_group).provider(COSMOS_DB_PROVIDER, "databaseAccounts", account_name)
+    }
+
+    /// Appends a provider namespace followed by a resource type and name
+    pub fn provider(
+        &self,
+        namespace: impl Into<String>,
+        resource_type: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.child(PROVIDERS, namespace).child(resource_type, name)
+    }
+
+    /// Appends a child resource type and name
+    pub fn child(&self, resource_type: impl Into<String>, name: impl Into<String>) -> Self {
+        let mut segments = self.segments.clone();
+        segments.push((resource_type.into(), name.into()));
+        Self { segments }
+    }
+
+    /// Gets the parent resource, skipping the provider segment
+    ///
+    /// The parent of a top-level resource is its resource group, and the parent of a
+    /// resource group is its subscription. A subscription has no parent.
+    pub fn parent(&self) -> Option<Self> {
+        let mut segments = self.segments.clone();
+        segments.pop();
+        while segments.last().is_some_and(|(key, _)| key.eq_ignore_ascii_case(PROVIDERS)) {
+            segments.pop();
+        }
+        (!segments.is_empty()).then_some(Self { segments })
+    }
+
+    /// Gets the subscription ID
+    pub fn subscription_id(&self) -> Option<&str> {
+        self.segment(SUBSCRIPTIONS)
+    }
+
+    /// Gets the resource group name
+    pub fn resource_group(&self) -> Option<&str> {
+        self.segment(RESOURCE_GROUPS)
+    }
+
+    /// Gets the innermost provider namespace (e.g. `Microsoft.DocumentDB`)
+    pub fn provider_namespace(&self) -> Option<&str> {
+        self.provider_index().map(|index| self.segments[index].1.as_str())
+    }
+
+    /// Gets the full resource type (e.g. `Microsoft.DocumentDB/databaseAccounts/sqlDatabases`)
+    ///
+    /// `None` for subscriptions and resource groups.
+    pub fn resource_type(&self) -> Option<String> {
+        let index = self.provider_index()?;
+        let types = &self.segments[index + 1..];
+        if types.is_empty() {
+            return None;
+        }
+
+        let mut resource_type = self.segments[index].1.clone();
+        for (key, _) in types {
+            resource_type.push('/');
+            resource_type.push_str(key);
+        }
+        Some(resource_type)
+    }
+
+    /// Gets the name of the resource (the last segment)
+    pub fn name(&self) -> &str {
+        &self.segments[self.segments.len() - 1].1
+    }
+
+    /// Gets the value following a segment key, compared case-insensitively
+    ///
+    /// For example `segment("databaseAccounts")` returns the account name of any
+    /// Cosmos DB account, database or container ID.
+    pub fn segment(&self, key: &str) -> Option<&str> {
+        self.segments
+            .iter()
+            .find(|(segment_key, _)| segment_key.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Gets the key/value segment pairs
+    pub fn segments(&self) -> &[(String, String)] {
+        &self.segments
+    }
+
+    /// Whether the resource type matches `resource_type`, compared case-insensitively
+    pub fn is_type(&self, resource_type: &str) -> bool {
+        self.resource_type()
+            .is_some_and(|actual| actual.eq_ignore_ascii_case(resource_type))
+    }
+
+    /// Fails with [`AzureError::InvalidResourceId`] unless the resource has the given type
+    pub fn expect_type(&self, resource_type: &str) -> Result<&Self> {
+        if self.is_type(resource_type) {
+            Ok(self)
+        } else {
+            Err(AzureError::InvalidResourceId {
+                id: self.to_string(),
+                reason: format!("expected a {} resource", resource_type),
+            })
+        }
+    }
+
+    /// Gets the value following a segment key, failing if it is absent
+    pub(crate) fn require_segment(&self, key: &str) -> Result<&str> {
+        self.segment(key).ok_or_else(|| AzureError::InvalidResourceId {
+            id: self.to_string(),
+            reason: format!("missing '{}' segment", key),
+        })
+    }
+
+    fn provider_index(&self) -> Option<usize> {
+        self.segments
+            .iter()
+            .rposition(|(key, _)| key.eq_ignore_ascii_case(PROVIDERS))
+    }
+}
+
+impl fmt::Display for AzureResourceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in &self.segments {
+            write!(f, "/{}/{}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for AzureResourceId {
+    type Err = AzureError;
+
+    fn from_str(id: &str) -> Result<Self> {
+        Self::parse(id)
+    }
+}
+
+impl TryFrom<String> for AzureResourceId {
+    type Error = AzureError;
+
+    fn try_from(id: String) -> Result<Self> {
+        Self::parse(&id)
+    }
+}
+
+impl From<AzureResourceId> for String {
+    fn from(id: AzureResourceId) -> Self {
+        id.to_string()
+    }
+}
+
+impl PartialEq for AzureResourceId {
+    fn eq(&self, other: &Self) -> bool {
+        self.segments.len() == other.segments.len()
+            && self.segments.iter().zip(&other.segments).all(|((key, value), (other_key, other_value))| {
+                key.eq_ignore_ascii_case(other_key) && value.eq_ignore_ascii_case(other_value)
+            })
+    }
+}
+
+impl Eq for AzureResourceId {}
+
+impl Hash for AzureResourceId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (key, value) in &self.segments {
+            key.to_ascii_lowercase().hash(state);
+            value.to_ascii_lowercase().hash(state);
+        }
+    }
+}
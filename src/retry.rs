@@ -0,0 +1,138 @@
+//! Retry policy for transient Azure CLI failures
+//!
+//! ARM throttling (HTTP 429), transient 5xx responses and dropped connections are
+//! retried with exponential backoff. Any other failure is returned immediately.
+
+use crate::error::AzureError;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Markers identifying transient failures in Azure CLI stderr (compared lowercase)
+const TRANSIENT_MARKERS: &[&str] = &[
+    "toomanyrequests",
+    "too many requests",
+    "(429)",
+    "status code 429",
+    "internalservererror",
+    "internal server error",
+    "(500)",
+    "badgateway",
+    "bad gateway",
+    "(502)",
+    "serviceunavailable",
+    "service unavailable",
+    "(503)",
+    "gatewaytimeout",
+    "gateway timeout",
+    "(504)",
+    "connection reset",
+    "connection aborted",
+    "connectionreseterror",
+    "remote end closed connection",
+    "temporarily unavailable",
+];
+
+/// Policy controlling how transient failures are retried
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every subsequent retry
+    pub base_delay: Duration,
+    /// Upper bound for any delay, whether computed or taken from a `Retry-After` hint
+    pub max_delay: Duration,
+    /// Whether to randomize delays so concurrent callers do not retry in lockstep
+    pub jitter: bool,
+    /// Whether a `Retry-After` hint found in stderr replaces the computed backoff
+    pub respect_retry_after: bool,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Creates the default policy with a custom number of attempts
+    pub fn with_max_attempts(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..Self::default()
+        }
+    }
+
+    /// Whether `error` should be retried after `attempt` attempts (starting at 1)
+    pub fn should_retry(&self, error: &AzureError, attempt: u32) -> bool {
+        attempt < self.max_attempts && error.is_transient()
+    }
+
+    /// Computes the delay before the next attempt after `attempt` attempts failed
+    pub fn delay_for(&self, error: &AzureError, attempt: u32) -> Duration {
+        if self.respect_retry_after
+            && let Some(hint) = error.retry_after()
+        {
+            return hint.min(self.max_delay);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter {
+            // Equal jitter: keep half of the backoff and randomize the other half
+            let half = backoff / 2;
+            half + half.mul_f64(random_fraction())
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts with exponential backoff starting at one second, capped at 30 seconds
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            respect_retry_after: true,
+        }
+    }
+}
+
+/// Whether Azure CLI stderr describes a transient failure worth retrying
+pub fn is_transient_stderr(stderr: &str) -> bool {
+    let stderr = stderr.to_lowercase();
+    TRANSIENT_MARKERS.iter().any(|marker| stderr.contains(marker))
+}
+
+/// Extracts a `Retry-After` hint (in seconds) from Azure CLI stderr
+///
+/// Recognizes both the raw header (`Retry-After: 30`) and prose such as
+/// `Please retry after 30 seconds`.
+pub fn retry_after_hint(stderr: &str) -> Option<Duration> {
+    let lowercase = stderr.to_ascii_lowercase();
+
+    ["retry-after", "retry after"].iter().find_map(|marker| {
+        let start = lowercase.find(marker)? + marker.len();
+        let digits: String = lowercase[start..]
+            .trim_start_matches(|c: char| c == ':' || c == '\'' || c == '"' || c.is_whitespace())
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        digits.parse().ok().map(Duration::from_secs)
+    })
+}
+
+/// Returns a pseudo-random number in `[0, 1)` without pulling in an RNG dependency
+fn random_fraction() -> f64 {
+    let value = RandomState::new().build_hasher().finish();
+    (value >> 11) as f64 / (1u64 << 53) as f64
+}
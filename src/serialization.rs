@@ -0,0 +1,705 @@
+//! Serialization styles for exporting models
+//!
+//! Models serialize in Azure's camelCase wire format ([`SerializationStyle::Arm`]), so
+//! `az` JSON → model → JSON is stable and exports can be fed back into ARM tooling.
+//! [`SerializationStyle::SnakeCase`] renames every modeled property to Rust-style snake_case
+//! instead, and can read such documents back. Keys of user data (tags, shard keys,
+//! user-assigned identities) and properties kept in `extra` are written as `az` returned them.
+//!
+//! ```
+//! use rust_az_wrapper::models::ConsistencyPolicy;
+//! use rust_az_wrapper::SerializationStyle;
+//!
+//! let policy: ConsistencyPolicy = serde_json::from_str(r#"{"defaultConsistencyLevel": "Session"}"#).unwrap();
+//!
+//! let snake = SerializationStyle::SnakeCase.to_value(&policy).unwrap();
+//! assert_eq!(snake["default_consistency_level"], "Session");
+//!
+//! let parsed: ConsistencyPolicy = SerializationStyle::SnakeCase.deserialize(snake).unwrap();
+//! assert_eq!(parsed.default_consistency_level, policy.default_consistency_level);
+//! ```
+
+use crate::error::Result;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, Impossible, Serialize, Serializer};
+use serde::{forward_to_deserialize_any, Deserializer};
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Properties whose values are maps keyed by user data (tag names, document fields,
+/// identity resource IDs), which are copied verbatim (compared after removing underscores, lowercase)
+const OPAQUE_PROPERTIES: &[&str] = &["tags", "shardkey", "userassignedidentities"];
+
+/// Newtype struct name marking the keys of a model's `extra` map while serializing
+const EXTRA_KEY: &str = "$rust_az_wrapper::ExtraKey";
+
+thread_local! {
+    /// For every snake_case object being read, innermost last, the camelCase names its keys
+    /// were read as, mapped back to the keys in the document
+    static RENAMED_KEYS: RefCell<Vec<HashMap<String, String>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Property naming used when serializing models
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SerializationStyle {
+    /// Azure Resource Manager camelCase, as returned by `az` (the `Serialize` default)
+    #[default]
+    Arm,
+    /// Rust-style snake_case
+    SnakeCase,
+}
+
+impl SerializationStyle {
+    /// Serializes a model to a JSON value in this style
+    pub fn to_value<T: Serialize + ?Sized>(self, model: &T) -> Result<Value> {
+        Ok(match self {
+            Self::Arm => serde_json::to_value(model)?,
+            Self::SnakeCase => model.serialize(SnakeCaseSerializer)?,
+        })
+    }
+
+    /// Serializes a model to pretty-printed JSON in this style
+    pub fn to_string_pretty<T: Serialize + ?Sized>(self, model: &T) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.to_value(model)?)?)
+    }
+
+    /// Deserializes a model from a JSON value written in this style
+    pub fn deserialize<T: DeserializeOwned>(self, value: Value) -> Result<T> {
+        Ok(match self {
+            Self::Arm => serde_json::from_value(value)?,
+            Self::SnakeCase => T::deserialize(SnakeCaseDeserializer(value))?,
+        })
+    }
+
+    /// Deserializes a model from JSON text written in this style
+    pub fn parse<T: DeserializeOwned>(self, json: &str) -> Result<T> {
+        self.deserialize(serde_json::from_str(json)?)
+    }
+}
+
+/// Converts a camelCase property name to snake_case (`defaultTtl` → `default_ttl`)
+pub fn camel_to_snake(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (index, c) in name.char_indices() {
+        if c.is_ascii_uppercase() {
+            if index > 0 && !name[..index].ends_with('_') {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// Converts a snake_case property name to camelCase (`default_ttl` → `defaultTtl`)
+///
+/// Leading underscores, as in `_rid` or `_etag`, are kept.
+pub fn snake_to_camel(name: &str) -> String {
+    let body = name.trim_start_matches('_');
+    let mut camel = name[..name.len() - body.len()].to_string();
+    let mut upper_next = false;
+    for c in body.chars() {
+        if c == '_' {
+            upper_next = true;
+        } else if upper_next {
+            camel.push(c.to_ascii_uppercase());
+            upper_next = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+fn is_opaque(key: &str) -> bool {
+    let normalized = key.replace('_', "").to_ascii_lowercase();
+    OPAQUE_PROPERTIES.contains(&normalized.as_str())
+}
+
+/// Serde adapter for the flattened `extra` map of every model
+///
+/// Serializing marks each unmodeled property name with [`EXTRA_KEY`], which `serde_json`
+/// ignores and the snake_case style uses to copy the property verbatim. Deserializing
+/// restores the names of unmodeled properties that were read as camelCase while looking
+/// for a matching field, so they end up in `extra` as written.
+pub(crate) mod extra {
+    use super::{ExtraKey, RENAMED_KEYS};
+    use serde::ser::SerializeMap;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use serde_json::{Map, Value};
+
+    pub fn serialize<S: Serializer>(extra: &Map<String, Value>, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(extra.len()))?;
+        for (key, value) in extra {
+            map.serialize_entry(&ExtraKey(key), value)?;
+        }
+        map.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Map<String, Value>, D::Error> {
+        let mut extra = Map::deserialize(deserializer)?;
+        RENAMED_KEYS.with(|frames| {
+            if let Some(renamed) = frames.borrow().last() {
+                for (camel, original) in renamed {
+                    if let Some(value) = extra.remove(camel) {
+                        extra.insert(original.clone(), value);
+                    }
+                }
+            }
+        });
+        Ok(extra)
+    }
+}
+
+/// Name of an unmodeled property, serialized as a newtype struct named [`EXTRA_KEY`]
+struct ExtraKey<'a>(&'a str);
+
+impl Serialize for ExtraKey<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(EXTRA_KEY, self.0)
+    }
+}
+
+/// Serializes a model to a [`Value`], renaming struct field names to snake_case
+struct SnakeCaseSerializer;
+
+macro_rules! serialize_as_json {
+    ($($method:ident($ty:ty)),+ $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> serde_json::Result<Value> {
+                serde_json::value::Serializer.$method(value)
+            }
+        )+
+    };
+}
+
+impl Serializer for SnakeCaseSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+    type SerializeSeq = SeqBuilder;
+    type SerializeTuple = SeqBuilder;
+    type SerializeTupleStruct = SeqBuilder;
+    type SerializeTupleVariant = VariantBuilder<SeqBuilder>;
+    type SerializeMap = MapBuilder;
+    type SerializeStruct = MapBuilder;
+    type SerializeStructVariant = VariantBuilder<MapBuilder>;
+
+    serialize_as_json! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+    }
+
+    fn serialize_none(self) -> serde_json::Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> serde_json::Result<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> serde_json::Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> serde_json::Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> serde_json::Result<Value> {
+        Ok(Value::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> serde_json::Result<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> serde_json::Result<Value> {
+        let mut map = Map::new();
+        map.insert(variant.to_string(), value.serialize(self)?);
+        Ok(Value::Object(map))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> serde_json::Result<SeqBuilder> {
+        Ok(SeqBuilder(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> serde_json::Result<SeqBuilder> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> serde_json::Result<SeqBuilder> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> serde_json::Result<VariantBuilder<SeqBuilder>> {
+        Ok(VariantBuilder { variant, inner: self.serialize_seq(Some(len))? })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> serde_json::Result<MapBuilder> {
+        Ok(MapBuilder::default())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> serde_json::Result<MapBuilder> {
+        Ok(MapBuilder::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> serde_json::Result<VariantBuilder<MapBuilder>> {
+        Ok(VariantBuilder { variant, inner: MapBuilder::default() })
+    }
+}
+
+struct SeqBuilder(Vec<Value>);
+
+impl SeqBuilder {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        self.0.push(value.serialize(SnakeCaseSerializer)?);
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for SeqBuilder {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        Ok(Value::Array(self.0))
+    }
+}
+
+impl ser::SerializeTuple for SeqBuilder {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        Ok(Value::Array(self.0))
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqBuilder {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        Ok(Value::Array(self.0))
+    }
+}
+
+/// Collects object properties; user data keys and opaque values are kept verbatim
+#[derive(Default)]
+struct MapBuilder {
+    map: Map<String, Value>,
+    key: Option<(String, bool)>,
+}
+
+impl MapBuilder {
+    fn insert<T: Serialize + ?Sized>(&mut self, key: String, verbatim: bool, value: &T) -> serde_json::Result<()> {
+        let value = if verbatim { serde_json::to_value(value)? } else { value.serialize(SnakeCaseSerializer)? };
+        self.map.insert(key, value);
+        Ok(())
+    }
+
+    fn insert_field<T: Serialize + ?Sized>(&mut self, name: &str, value: &T) -> serde_json::Result<()> {
+        self.insert(camel_to_snake(name), is_opaque(name), value)
+    }
+}
+
+impl ser::SerializeMap for MapBuilder {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_key<K: Serialize + ?Sized>(&mut self, key: &K) -> serde_json::Result<()> {
+        self.key = Some(match key.serialize(KeySerializer)? {
+            MapKey::Extra(key) => (key, true),
+            MapKey::Field(key) => {
+                let opaque = is_opaque(&key);
+                (camel_to_snake(&key), opaque)
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        let (key, verbatim) = self.key.take().ok_or_else(|| ser::Error::custom("map value serialized before its key"))?;
+        self.insert(key, verbatim, value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        Ok(Value::Object(self.map))
+    }
+}
+
+impl ser::SerializeStruct for MapBuilder {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, name: &'static str, value: &T) -> serde_json::Result<()> {
+        self.insert_field(name, value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        Ok(Value::Object(self.map))
+    }
+}
+
+/// A serialized map key
+enum MapKey {
+    /// Field name of a model, or key of a map nested in one
+    Field(String),
+    /// Name of an unmodeled property from a model's `extra` map
+    Extra(String),
+}
+
+/// Serializes a map key, recognizing keys marked with [`EXTRA_KEY`]
+struct KeySerializer;
+
+macro_rules! serialize_key_to_string {
+    ($($method:ident($ty:ty)),+ $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> serde_json::Result<MapKey> {
+                Ok(MapKey::Field(value.to_string()))
+            }
+        )+
+    };
+}
+
+impl Serializer for KeySerializer {
+    type Ok = MapKey;
+    type Error = serde_json::Error;
+    type SerializeSeq = Impossible<MapKey, serde_json::Error>;
+    type SerializeTuple = Impossible<MapKey, serde_json::Error>;
+    type SerializeTupleStruct = Impossible<MapKey, serde_json::Error>;
+    type SerializeTupleVariant = Impossible<MapKey, serde_json::Error>;
+    type SerializeMap = Impossible<MapKey, serde_json::Error>;
+    type SerializeStruct = Impossible<MapKey, serde_json::Error>;
+    type SerializeStructVariant = Impossible<MapKey, serde_json::Error>;
+
+    serialize_key_to_string! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+    }
+
+    fn serialize_bytes(self, _value: &[u8]) -> serde_json::Result<MapKey> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_none(self) -> serde_json::Result<MapKey> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> serde_json::Result<MapKey> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> serde_json::Result<MapKey> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> serde_json::Result<MapKey> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> serde_json::Result<MapKey> {
+        Ok(MapKey::Field(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, name: &'static str, value: &T) -> serde_json::Result<MapKey> {
+        match value.serialize(self)? {
+            MapKey::Field(key) if name == EXTRA_KEY => Ok(MapKey::Extra(key)),
+            key => Ok(key),
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> serde_json::Result<MapKey> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> serde_json::Result<Self::SerializeSeq> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> serde_json::Result<Self::SerializeTuple> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> serde_json::Result<Self::SerializeTupleStruct> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> serde_json::Result<Self::SerializeTupleVariant> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> serde_json::Result<Self::SerializeMap> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> serde_json::Result<Self::SerializeStruct> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> serde_json::Result<Self::SerializeStructVariant> {
+        Err(key_must_be_a_string())
+    }
+}
+
+fn key_must_be_a_string() -> serde_json::Error {
+    ser::Error::custom("map key must be a string")
+}
+
+/// Wraps the content of a tuple or struct enum variant as `{ "variant": content }`
+struct VariantBuilder<T> {
+    variant: &'static str,
+    inner: T,
+}
+
+impl<T> VariantBuilder<T> {
+    fn wrap(variant: &str, content: Value) -> Value {
+        let mut map = Map::new();
+        map.insert(variant.to_string(), content);
+        Value::Object(map)
+    }
+}
+
+impl ser::SerializeTupleVariant for VariantBuilder<SeqBuilder> {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        Ok(Self::wrap(self.variant, Value::Array(self.inner.0)))
+    }
+}
+
+impl ser::SerializeStructVariant for VariantBuilder<MapBuilder> {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, name: &'static str, value: &T) -> serde_json::Result<()> {
+        self.inner.insert_field(name, value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        Ok(Self::wrap(self.variant, Value::Object(self.inner.map)))
+    }
+}
+
+/// Deserializes a model from a snake_case [`Value`]
+///
+/// Only keys read as struct field identifiers are renamed back to camelCase; keys of maps
+/// and of properties collected into `extra` are passed through unchanged.
+struct SnakeCaseDeserializer(Value);
+
+impl<'de> Deserializer<'de> for SnakeCaseDeserializer {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Object(map) => {
+                let _frame = RenamedKeysFrame::push();
+                visitor.visit_map(SnakeCaseMapAccess { entries: map.into_iter(), value: None })
+            }
+            Value::Array(items) => visitor.visit_seq(SnakeCaseSeqAccess(items.into_iter())),
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            other => visitor.visit_some(SnakeCaseDeserializer(other)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> serde_json::Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct SnakeCaseSeqAccess(std::vec::IntoIter<Value>);
+
+impl<'de> SeqAccess<'de> for SnakeCaseSeqAccess {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> serde_json::Result<Option<T::Value>> {
+        self.0.next().map(|item| seed.deserialize(SnakeCaseDeserializer(item))).transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct SnakeCaseMapAccess {
+    entries: serde_json::map::IntoIter,
+    value: Option<(Value, bool)>,
+}
+
+impl<'de> MapAccess<'de> for SnakeCaseMapAccess {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> serde_json::Result<Option<K::Value>> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.value = Some((value, is_opaque(&key)));
+        seed.deserialize(KeyDeserializer(key)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> serde_json::Result<V::Value> {
+        match self.value.take() {
+            Some((value, true)) => seed.deserialize(value),
+            Some((value, false)) => seed.deserialize(SnakeCaseDeserializer(value)),
+            None => Err(de::Error::custom("map value read before its key")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+/// Records the keys renamed while reading one object, for [`extra::deserialize`]
+struct RenamedKeysFrame;
+
+impl RenamedKeysFrame {
+    fn push() -> Self {
+        RENAMED_KEYS.with(|frames| frames.borrow_mut().push(HashMap::new()));
+        Self
+    }
+
+    fn record(camel: &str, original: &str) {
+        RENAMED_KEYS.with(|frames| {
+            if let Some(renamed) = frames.borrow_mut().last_mut() {
+                renamed.insert(camel.to_string(), original.to_string());
+            }
+        });
+    }
+}
+
+impl Drop for RenamedKeysFrame {
+    fn drop(&mut self) {
+        RENAMED_KEYS.with(|frames| frames.borrow_mut().pop());
+    }
+}
+
+/// Renames a key only when it is read as a struct field identifier
+///
+/// A struct with a flattened `extra` map reads every key as an identifier, so renamed keys
+/// are recorded and [`extra::deserialize`] restores those that matched no field.
+struct KeyDeserializer(String);
+
+impl<'de> Deserializer<'de> for KeyDeserializer {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        self.0.into_deserializer().deserialize_any(visitor)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        let camel = snake_to_camel(&self.0);
+        if camel != self.0 {
+            RenamedKeysFrame::record(&camel, &self.0);
+        }
+        visitor.visit_string(camel)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct map struct enum ignored_any
+    }
+}
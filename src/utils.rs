@@ -1,81 +1,172 @@
 //! Utilities for executing Azure CLI commands
 
 use crate::error::{AzureError, Result};
+use crate::executor::{CommandExecutor, ProcessExecutor};
+use crate::retry::RetryPolicy;
 use serde::de::DeserializeOwned;
-use std::process::{Command, Stdio};
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-/// Executes an Azure CLI command and returns the result as parsed JSON
-pub async fn execute_az_command<T>(args: &[&str]) -> Result<T>
+/// Settings shared by every command issued through an [`AzureClient`](crate::AzureClient)
+#[derive(Clone)]
+pub struct ExecutionContext {
+    executor: Arc<dyn CommandExecutor>,
+    timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+}
+
+impl ExecutionContext {
+    /// Creates a context that dispatches commands to the given executor
+    pub fn new<E>(executor: E) -> Self
+    where
+        E: CommandExecutor + 'static,
+    {
+        Self {
+            executor: Arc::new(executor),
+            timeout: None,
+            retry_policy: RetryPolicy::none(),
+        }
+    }
+
+    /// Replaces the executor while keeping the other settings
+    pub fn with_executor<E>(mut self, executor: E) -> Self
+    where
+        E: CommandExecutor + 'static,
+    {
+        self.executor = Arc::new(executor);
+        self
+    }
+
+    /// Sets the default timeout applied to every command (`None` waits indefinitely)
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Gets the default timeout applied to every command
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Sets the policy used to retry transient failures
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Gets the policy used to retry transient failures
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Gets the executor commands are dispatched to
+    pub fn executor(&self) -> &dyn CommandExecutor {
+        self.executor.as_ref()
+    }
+
+    /// Runs a command and returns its stdout, mapping failures to [`AzureError`]
+    /// 
+    /// Transient failures are retried according to the retry policy.
+    async fn run(&self, args: &[String], timeout: Option<Duration>) -> Result<String> {
+        let mut attempt = 1;
+
+        loop {
+            match self.run_once(args, timeout).await {
+                Err(error) if self.retry_policy.should_retry(&error, attempt) => {
+                    tokio::time::sleep(self.retry_policy.delay_for(&error, attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Runs a single attempt of a command
+    /// 
+    /// `timeout` overrides the context default. On expiry the executor future is
+    /// dropped, which kills the underlying process.
+    async fn run_once(&self, args: &[String], timeout: Option<Duration>) -> Result<String> {
+        let command = format!("az {}", args.join(" "));
+
+        let output = match timeout.or(self.timeout) {
+            Some(limit) => {
+                let started = Instant::now();
+                tokio::time::timeout(limit, self.executor.execute(args))
+                    .await
+                    .map_err(|_| AzureError::Timeout {
+                        command: command.clone(),
+                        elapsed: started.elapsed(),
+                    })??
+            }
+            None => self.executor.execute(args).await?,
+        };
+
+        if !output.is_success() {
+            return Err(AzureError::from_command_output(&command, &output));
+        }
+
+        Ok(output.stdout)
+    }
+}
+
+impl Default for ExecutionContext {
+    fn default() -> Self {
+        Self::new(ProcessExecutor::new())
+    }
+}
+
+impl fmt::Debug for ExecutionContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExecutionContext")
+            .field("timeout", &self.timeout)
+            .field("retry_policy", &self.retry_policy)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Executes an Azure CLI command through the given context and returns the result as parsed JSON
+pub async fn execute_az_command<T>(context: &ExecutionContext, args: &[&str]) -> Result<T>
 where
     T: DeserializeOwned,
 {
-    let output = execute_az_command_raw(args).await?;
-    
-    if output.is_empty() {
-        return Err(AzureError::CliExecution {
-            command: format!("az {}", args.join(" ")),
-            error: "Empty output".to_string(),
-        });
-    }
-
-    let parsed: T = serde_json::from_str(&output)?;
-    Ok(parsed)
+    args.iter()
+        .fold(AzCommandBuilder::with_context(context), |builder, arg| builder.subcommand(arg))
+        .execute()
+        .await
 }
 
-/// Executes an Azure CLI command and returns the raw output as string
-pub async fn execute_az_command_raw(args: &[&str]) -> Result<String> {
-    let mut command = Command::new("az");
-    command
-        .args(args)
-        .arg("--output")
-        .arg("json")
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
-
-    let output = command.output()?;
-    
-    if !output.status.success() {
-        return Err(AzureError::from_command_output(
-            &format!("az {}", args.join(" ")),
-            output,
-        ));
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    Ok(stdout.to_string())
+/// Executes an Azure CLI command through the given context and returns the raw output as string
+pub async fn execute_az_command_raw(context: &ExecutionContext, args: &[&str]) -> Result<String> {
+    args.iter()
+        .fold(AzCommandBuilder::with_context(context), |builder, arg| builder.subcommand(arg))
+        .execute_raw()
+        .await
 }
 
-/// Checks if Azure CLI is installed and accessible
-pub async fn check_az_cli() -> Result<()> {
-    let mut command = Command::new("az");
-    command
-        .arg("--version")
-        .stdout(Stdio::null())
-        .stderr(Stdio::null());
-
-    match command.output() {
-        Ok(output) => {
-            if output.status.success() {
-                Ok(())
-            } else {
-                Err(AzureError::CliNotFound)
-            }
-        }
+/// Checks if Azure CLI is installed and accessible by running `az --version`
+/// 
+/// Fails with [`AzureError::CliNotFound`] when the command cannot be run or exits with an
+/// error. The context timeout applies: a hung `az` is killed and reported as
+/// [`AzureError::Timeout`].
+pub async fn check_az_cli(context: &ExecutionContext) -> Result<()> {
+    match AzCommandBuilder::with_context(context).flag("--version").execute_raw().await {
+        Ok(_) => Ok(()),
+        Err(error @ AzureError::Timeout { .. }) => Err(error),
         Err(_) => Err(AzureError::CliNotFound),
     }
 }
 
 /// Checks if the user is authenticated with Azure CLI
-pub async fn check_authentication() -> Result<()> {
-    let result = execute_az_command_raw(&["account", "show"]).await;
-    
-    match result {
-        Ok(_) => Ok(()),
-        Err(AzureError::CliError { stderr, .. }) if stderr.contains("az login") => {
-            Err(AzureError::Authentication)
-        }
-        Err(e) => Err(e),
-    }
+/// 
+/// Fails with [`AzureError::Authentication`] when Azure CLI asks for `az login`.
+pub async fn check_authentication(context: &ExecutionContext) -> Result<()> {
+    AzCommandBuilder::with_context(context)
+        .subcommand("account")
+        .subcommand("show")
+        .execute_raw()
+        .await
+        .map(|_| ())
 }
 
 /// Formats optional parameters for Azure CLI commands
@@ -98,12 +189,23 @@ pub fn format_bool_param(param: &str, value: Option<bool>) -> Vec<String> {
 /// Builds arguments for Azure CLI commands
 pub struct AzCommandBuilder {
     args: Vec<String>,
+    context: ExecutionContext,
+    timeout: Option<Duration>,
 }
 
 impl AzCommandBuilder {
-    /// Creates a new builder
+    /// Creates a new builder that runs the installed Azure CLI
     pub fn new() -> Self {
-        Self { args: Vec::new() }
+        Self::with_context(&ExecutionContext::default())
+    }
+
+    /// Creates a new builder that executes through the given context
+    pub fn with_context(context: &ExecutionContext) -> Self {
+        Self {
+            args: Vec::new(),
+            context: context.clone(),
+            timeout: None,
+        }
     }
 
     /// Adds a subcommand
@@ -151,6 +253,12 @@ impl AzCommandBuilder {
         self
     }
 
+    /// Overrides the context timeout for this command
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Builds the final arguments
     pub fn build(self) -> Vec<String> {
         self.args
@@ -161,14 +269,23 @@ impl AzCommandBuilder {
     where
         T: DeserializeOwned,
     {
-        let args: Vec<&str> = self.args.iter().map(|s| s.as_str()).collect();
-        execute_az_command(&args).await
+        let command = format!("az {}", self.args.join(" "));
+        let output = self.execute_raw().await?;
+
+        if output.is_empty() {
+            return Err(AzureError::CliExecution {
+                command,
+                error: "Empty output".to_string(),
+            });
+        }
+
+        let parsed: T = serde_json::from_str(&output)?;
+        Ok(parsed)
     }
 
     /// Executes the command and returns the raw result
     pub async fn execute_raw(self) -> Result<String> {
-        let args: Vec<&str> = self.args.iter().map(|s| s.as_str()).collect();
-        execute_az_command_raw(&args).await
+        self.context.run(&self.args, self.timeout).await
     }
 }
 
@@ -0,0 +1,131 @@
+//! Tests for classification of Azure CLI stderr into typed errors
+
+use rust_az_wrapper::AzureError;
+use std::time::Duration;
+
+#[test]
+fn test_resource_not_found_from_arm_message() {
+    let stderr = "ERROR: (ResourceNotFound) The Resource 'Microsoft.DocumentDB/databaseAccounts/prod-cosmos' under resource group 'prod-rg' was not found. For more details please go to https://aka.ms/ARMResourceNotFoundFix\n\
+Code: ResourceNotFound\n\
+Message: The Resource 'Microsoft.DocumentDB/databaseAccounts/prod-cosmos' under resource group 'prod-rg' was not found.";
+
+    let error = AzureError::from_stderr("az cosmosdb show --name prod-cosmos --resource-group prod-rg", stderr);
+
+    assert_eq!(error.error_code(), Some("ResourceNotFound"));
+    match error {
+        AzureError::ResourceNotFound { resource_type, name, .. } => {
+            assert_eq!(resource_type.as_deref(), Some("Microsoft.DocumentDB/databaseAccounts"));
+            assert_eq!(name.as_deref(), Some("prod-cosmos"));
+        }
+        other => panic!("expected ResourceNotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_missing_container_is_resource_not_found() {
+    let stderr = "ERROR: (NotFound) Message: {\"code\":\"NotFound\",\"message\":\"Message: {\\\"Errors\\\":[\\\"Resource Not Found.\\\"]}\\r\\nActivityId: 3f1c9c1e-0a7b-4c1e-9d55-2b0f1e6c7a10, Request URI: /apps/x/services/y\"}";
+
+    let error = AzureError::from_stderr(
+        "az cosmosdb sql container show --account-name acc --resource-group rg --database-name db --name orders",
+        stderr,
+    );
+
+    assert_eq!(error.correlation_id(), Some("3f1c9c1e-0a7b-4c1e-9d55-2b0f1e6c7a10"));
+    match error {
+        AzureError::ResourceNotFound { resource_type, name, .. } => {
+            assert_eq!(resource_type.as_deref(), Some("cosmosdb sql container"));
+            assert_eq!(name.as_deref(), Some("orders"));
+        }
+        other => panic!("expected ResourceNotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_authorization_failed_extracts_action_and_scope() {
+    let stderr = "ERROR: (AuthorizationFailed) The client 'user@contoso.com' with object id '00000000-0000-0000-0000-000000000001' does not have authorization to perform action 'Microsoft.DocumentDB/databaseAccounts/read' over scope '/subscriptions/sub-1/resourceGroups/rg' or the scope is invalid.\n\
+Code: AuthorizationFailed";
+
+    match AzureError::from_stderr("az cosmosdb list --resource-group rg", stderr) {
+        AzureError::AuthorizationFailed { action, scope, .. } => {
+            assert_eq!(action.as_deref(), Some("Microsoft.DocumentDB/databaseAccounts/read"));
+            assert_eq!(scope.as_deref(), Some("/subscriptions/sub-1/resourceGroups/rg"));
+        }
+        other => panic!("expected AuthorizationFailed, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_subscription_not_found() {
+    let stderr = "ERROR: Subscription 'missing-sub' not found. Check the spelling and casing and try again.";
+
+    match AzureError::from_stderr("az cosmosdb list --subscription missing-sub", stderr) {
+        AzureError::SubscriptionNotFound { subscription, .. } => {
+            assert_eq!(subscription.as_deref(), Some("missing-sub"));
+        }
+        other => panic!("expected SubscriptionNotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_throttled_with_retry_after_and_tracking_id() {
+    let stderr = "ERROR: (TooManyRequests) Number of read requests for subscription exceeded the limit. Please retry after 20 seconds. Tracking ID: '1a2b3c4d-5e6f-7081-92a3-b4c5d6e7f809'";
+
+    let error = AzureError::from_stderr("az cosmosdb list", stderr);
+
+    assert!(error.is_transient());
+    assert_eq!(error.correlation_id(), Some("1a2b3c4d-5e6f-7081-92a3-b4c5d6e7f809"));
+    match error {
+        AzureError::Throttled { retry_after, .. } => {
+            assert_eq!(retry_after, Some(Duration::from_secs(20)));
+        }
+        other => panic!("expected Throttled, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_extension_required() {
+    let stderr = "ERROR: The command requires the extension cosmosdb-preview. Unable to prompt for extension install confirmation as no tty available. Run 'az config set extension.use_dynamic_install=yes_without_prompt' to allow installing extensions without prompt.";
+
+    match AzureError::from_stderr("az cosmosdb sql container show", stderr) {
+        AzureError::ExtensionRequired { extension, .. } => {
+            assert_eq!(extension.as_deref(), Some("cosmosdb-preview"));
+        }
+        other => panic!("expected ExtensionRequired, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_invalid_argument() {
+    let stderr = "ERROR: the following arguments are required: --resource-group/-g";
+
+    match AzureError::from_stderr("az cosmosdb show --name acc", stderr) {
+        AzureError::InvalidArgument { argument, details } => {
+            assert_eq!(argument.as_deref(), Some("--resource-group/-g"));
+            assert_eq!(details.message, "the following arguments are required: --resource-group/-g");
+        }
+        other => panic!("expected InvalidArgument, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_login_prompt_is_authentication_error() {
+    let stderr = "ERROR: Please run 'az login' to setup account.";
+
+    assert!(matches!(
+        AzureError::from_stderr("az account show", stderr),
+        AzureError::Authentication
+    ));
+}
+
+#[test]
+fn test_unclassified_error_keeps_stderr() {
+    let stderr = "ERROR: (Conflict) Operation failed.";
+
+    let error = AzureError::from_stderr("az cosmosdb show --name acc --resource-group rg", stderr);
+
+    assert_eq!(error.error_code(), Some("Conflict"));
+    match error {
+        AzureError::CliError { details } => assert_eq!(details.stderr, stderr),
+        other => panic!("expected CliError, got {:?}", other),
+    }
+}
@@ -0,0 +1,185 @@
+//! Tests for model parsing and helpers that do not involve the Azure CLI
+
+use rust_az_wrapper::models::{
+    AccountKind, BackupType, ConsistencyLevel, ContinuousBackupTier, CosmosAccount, CosmosContainer, ExtraFields,
+    PartitionKey, PartitionKeyKind, PartitionKeyViolation, ProvisioningState, PublicNetworkAccess,
+};
+use rust_az_wrapper::recording::Fixture;
+use rust_az_wrapper::AzureError;
+use serde_json::{json, Value};
+
+fn hierarchical_key() -> PartitionKey {
+    serde_json::from_value(json!({
+        "kind": "MultiHash",
+        "paths": ["/tenantId", "/address/zipCode", "/userId"],
+        "systemKey": null,
+        "version": 2
+    }))
+    .expect("partition key should parse")
+}
+
+#[test]
+fn test_partition_key_kind_parsing() {
+    let key = hierarchical_key();
+    assert_eq!(key.kind, PartitionKeyKind::MultiHash);
+    assert!(key.is_hierarchical());
+    assert_eq!(key.depth(), 3);
+    assert!(key.depth() <= PartitionKey::MAX_HIERARCHY_DEPTH);
+
+    let unknown: PartitionKeyKind = serde_json::from_value(json!("Spatial")).unwrap();
+    assert_eq!(unknown, PartitionKeyKind::Unknown("Spatial".to_string()));
+    assert_eq!(serde_json::to_value(&unknown).unwrap(), json!("Spatial"));
+    assert_eq!(serde_json::to_value(PartitionKeyKind::MultiHash).unwrap(), json!("MultiHash"));
+}
+
+#[test]
+fn test_partition_key_levels() {
+    let key = hierarchical_key();
+    let levels = key.levels();
+
+    assert_eq!(levels.len(), 3);
+    assert_eq!(levels[0].level, 1);
+    assert_eq!(levels[1].path, "/address/zipCode");
+    assert_eq!(levels[1].segments, vec!["address", "zipCode"]);
+}
+
+#[test]
+fn test_partition_key_document_validation() {
+    let key = hierarchical_key();
+
+    let valid = json!({ "tenantId": "contoso", "address": { "zipCode": 98052 }, "userId": null });
+    assert!(key.validate_document(&valid).is_empty());
+    assert_eq!(key.values(&valid)[1], Some(&json!(98052)));
+
+    let invalid = json!({ "tenantId": ["contoso"], "address": {}, "userId": "u1" });
+    assert_eq!(
+        key.validate_document(&invalid),
+        vec![
+            PartitionKeyViolation::InvalidType { path: "/tenantId".to_string() },
+            PartitionKeyViolation::Missing { path: "/address/zipCode".to_string() },
+        ]
+    );
+}
+
+#[test]
+fn test_account_enums_round_trip() {
+    let account: CosmosAccount = serde_json::from_value(json!({
+        "name": "contoso-cosmos",
+        "id": "/subscriptions/sub/resourceGroups/rg/providers/Microsoft.DocumentDB/databaseAccounts/contoso-cosmos",
+        "location": "East US",
+        "resourceGroup": "rg",
+        "type": "Microsoft.DocumentDB/databaseAccounts",
+        "kind": "MongoDB",
+        "provisioningState": "succeeded",
+        "documentEndpoint": "https://contoso-cosmos.documents.azure.com:443/",
+        "publicNetworkAccess": "SecuredByPerimeter",
+        "consistencyPolicy": { "defaultConsistencyLevel": "BoundedStaleness" },
+        "backupPolicy": { "type": "Continuous", "continuousModeProperties": { "tier": "Continuous30Days" } }
+    }))
+    .expect("account should parse");
+
+    assert_eq!(account.kind, AccountKind::MongoDB);
+    assert_eq!(account.provisioning_state, ProvisioningState::Succeeded);
+    assert_eq!(account.public_network_access, Some(PublicNetworkAccess::SecuredByPerimeter));
+    let consistency = account.consistency_policy.as_ref().unwrap();
+    assert!(matches!(consistency.default_consistency_level, ConsistencyLevel::BoundedStaleness));
+    let backup = account.backup_policy.as_ref().unwrap();
+    assert_eq!(backup.backup_type, BackupType::Continuous);
+    assert_eq!(
+        backup.continuous_mode_properties.as_ref().and_then(|props| props.tier.clone()),
+        Some(ContinuousBackupTier::Continuous30Days)
+    );
+
+    // Known values serialize with Azure's casing
+    let value = serde_json::to_value(&account).unwrap();
+    assert_eq!(value["provisioningState"], "Succeeded");
+}
+
+#[test]
+fn test_unknown_enum_values_are_preserved() {
+    let level: ConsistencyLevel = serde_json::from_value(json!("Linearizable")).unwrap();
+    assert_eq!(level, ConsistencyLevel::Unknown("Linearizable".to_string()));
+    assert_eq!(level.to_string(), "Linearizable");
+    assert_eq!(serde_json::to_value(&level).unwrap(), json!("Linearizable"));
+
+    assert_eq!(AccountKind::from("globaldocumentdb"), AccountKind::GlobalDocumentDB);
+    assert_eq!(AccountKind::GlobalDocumentDB.as_str(), "GlobalDocumentDB");
+}
+
+#[test]
+fn test_unknown_fields_are_preserved() {
+    let key: PartitionKey = serde_json::from_value(json!({
+        "kind": "Hash",
+        "paths": ["/id"],
+        "futureProperty": { "enabled": true }
+    }))
+    .unwrap();
+
+    assert_eq!(key.extra_field("futureProperty"), Some(&json!({ "enabled": true })));
+    assert_eq!(serde_json::to_value(&key).unwrap()["futureProperty"]["enabled"], true);
+}
+
+#[test]
+fn test_container_round_trip_is_lossless() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/cosmos.json");
+    let fixture = Fixture::load(path).expect("fixture should load");
+    let original = fixture
+        .interactions
+        .iter()
+        .find(|interaction| interaction.args.starts_with(&["cosmosdb".into(), "sql".into(), "container".into(), "show".into()]))
+        .and_then(|interaction| interaction.stdout.clone())
+        .expect("fixture should contain a container");
+
+    let container: CosmosContainer = serde_json::from_value(original.clone()).unwrap();
+    let resource = container.resource.as_ref().unwrap();
+    assert_eq!(resource.extra_field("_docs"), Some(&json!("docs/")));
+
+    // Absent optional properties come back as `null`; everything else is preserved
+    assert_eq!(without_nulls(serde_json::to_value(&container).unwrap()), without_nulls(original));
+}
+
+#[test]
+fn test_malformed_nested_ids_do_not_fail_the_account() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/cosmos.json");
+    let fixture = Fixture::load(path).expect("fixture should load");
+    let mut accounts = fixture
+        .interactions
+        .iter()
+        .find(|interaction| interaction.args.starts_with(&["cosmosdb".into(), "list".into()]))
+        .and_then(|interaction| interaction.stdout.clone())
+        .expect("fixture should contain an account list");
+
+    let subnet = "/subscriptions/00000000-0000-0000-0000-000000000001/resourceGroups/network-rg/providers/Microsoft.Network/virtualNetworks/vnet/subnets/default";
+    accounts[0]["virtualNetworkRules"] = json!([
+        { "id": subnet, "ignoreMissingVnetServiceEndpoint": false },
+        { "id": "subnets/default" }
+    ]);
+    accounts[0]["privateEndpointConnections"] = json!([{
+        "id": null,
+        "privateEndpoint": { "id": "not-an-id" }
+    }]);
+
+    let accounts: Vec<CosmosAccount> = serde_json::from_value(accounts).expect("accounts should still parse");
+    let rules = accounts[0].virtual_network_rules.as_ref().unwrap();
+    assert_eq!(rules[0].resource_id().unwrap().name(), "default");
+    assert!(matches!(rules[1].resource_id(), Err(AzureError::InvalidResourceId { .. })));
+
+    let connection = &accounts[0].private_endpoint_connections.as_ref().unwrap()[0];
+    assert!(connection.resource_id().is_none());
+    let endpoint = connection.private_endpoint.as_ref().unwrap();
+    assert!(matches!(endpoint.resource_id(), Err(AzureError::InvalidResourceId { .. })));
+}
+
+/// Removes `null` properties so documents can be compared regardless of absent options
+fn without_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, without_nulls(value)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(without_nulls).collect()),
+        other => other,
+    }
+}
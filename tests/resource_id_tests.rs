@@ -0,0 +1,80 @@
+//! Tests for Azure resource ID parsing and building
+
+use rust_az_wrapper::{AzureError, AzureResourceId};
+use std::collections::HashSet;
+
+const CONTAINER_ID: &str = "/subscriptions/00000000-0000-0000-0000-000000000001/resourceGroups/cosmos-rg/providers/Microsoft.DocumentDB/databaseAccounts/contoso-cosmos/sqlDatabases/appdb/containers/orders";
+
+#[test]
+fn test_parse_segments() {
+    let id: AzureResourceId = CONTAINER_ID.parse().unwrap();
+
+    assert_eq!(id.subscription_id(), Some("00000000-0000-0000-0000-000000000001"));
+    assert_eq!(id.resource_group(), Some("cosmos-rg"));
+    assert_eq!(id.provider_namespace(), Some("Microsoft.DocumentDB"));
+    assert_eq!(
+        id.resource_type().as_deref(),
+        Some("Microsoft.DocumentDB/databaseAccounts/sqlDatabases/containers")
+    );
+    assert_eq!(id.name(), "orders");
+    assert_eq!(id.segment("databaseaccounts"), Some("contoso-cosmos"));
+    assert_eq!(id.segment("sqlDatabases"), Some("appdb"));
+    assert_eq!(id.to_string(), CONTAINER_ID);
+}
+
+#[test]
+fn test_parent_walk() {
+    let id: AzureResourceId = CONTAINER_ID.parse().unwrap();
+
+    let database = id.parent().unwrap();
+    assert_eq!(database.name(), "appdb");
+    let account = database.parent().unwrap();
+    assert!(account.is_type("microsoft.documentdb/databaseaccounts"));
+    let resource_group = account.parent().unwrap();
+    assert_eq!(resource_group, AzureResourceId::resource_group_id("00000000-0000-0000-0000-000000000001", "cosmos-rg"));
+    assert_eq!(resource_group.resource_type(), None);
+    let subscription = resource_group.parent().unwrap();
+    assert_eq!(subscription.parent(), None);
+}
+
+#[test]
+fn test_build_child_ids() {
+    let built = AzureResourceId::cosmos_account("00000000-0000-0000-0000-000000000001", "cosmos-rg", "contoso-cosmos")
+        .child("sqlDatabases", "appdb")
+        .child("containers", "orders");
+
+    assert_eq!(built.to_string(), CONTAINER_ID);
+}
+
+#[test]
+fn test_case_insensitive_comparison() {
+    let id: AzureResourceId = CONTAINER_ID.parse().unwrap();
+    let upper: AzureResourceId = CONTAINER_ID.to_uppercase().parse().unwrap();
+
+    assert_eq!(id, upper);
+    assert_eq!(HashSet::from([id.clone(), upper]).len(), 1);
+    assert!(id.expect_type("Microsoft.DocumentDB/databaseAccounts").is_err());
+}
+
+#[test]
+fn test_invalid_ids() {
+    for invalid in [
+        "",
+        "subscriptions/sub",
+        "/subscriptions/sub/resourceGroups",
+        "/subscriptions//resourceGroups/rg",
+        "/resourceGroups/rg/subscriptions/sub",
+        "/foo/bar",
+    ] {
+        let result = invalid.parse::<AzureResourceId>();
+        assert!(matches!(result, Err(AzureError::InvalidResourceId { .. })), "{:?} should be rejected", invalid);
+    }
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let id: AzureResourceId = serde_json::from_value(serde_json::json!(CONTAINER_ID)).unwrap();
+    assert_eq!(serde_json::to_value(&id).unwrap(), serde_json::json!(CONTAINER_ID));
+
+    assert!(serde_json::from_value::<AzureResourceId>(serde_json::json!("not-an-id")).is_err());
+}
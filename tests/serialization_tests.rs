@@ -0,0 +1,253 @@
+//! Tests that models serialize back to the JSON `az` returned
+
+use rust_az_wrapper::models::*;
+use rust_az_wrapper::recording::{Fixture, Interaction};
+use rust_az_wrapper::serialization::{camel_to_snake, snake_to_camel};
+use rust_az_wrapper::SerializationStyle;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+fn load_fixture(name: &str) -> Fixture {
+    let path = format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    Fixture::load(path).expect("fixture should load")
+}
+
+/// Removes `null` properties so documents can be compared regardless of absent options
+fn without_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, without_nulls(value)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(without_nulls).collect()),
+        other => other,
+    }
+}
+
+/// Checks `az` JSON → model → JSON in both styles
+fn assert_round_trip<T: Serialize + DeserializeOwned>(command: &str, original: &Value) {
+    let model: T = serde_json::from_value(original.clone())
+        .unwrap_or_else(|e| panic!("'{}' output should parse: {}", command, e));
+
+    let arm = SerializationStyle::Arm.to_value(&model).unwrap();
+    assert_eq!(without_nulls(arm.clone()), without_nulls(original.clone()), "ARM round trip of '{}'", command);
+
+    let snake = SerializationStyle::SnakeCase.to_value(&model).unwrap();
+    let back: T = SerializationStyle::SnakeCase.deserialize(snake).unwrap();
+    assert_eq!(SerializationStyle::Arm.to_value(&back).unwrap(), arm, "snake_case round trip of '{}'", command);
+}
+
+fn check_interaction(interaction: &Interaction) -> bool {
+    let Some(stdout) = interaction.stdout.as_ref() else {
+        return false;
+    };
+    let command = interaction.args.join(" ");
+    let starts = |prefix: &str| command == prefix || command.starts_with(&format!("{} ", prefix));
+
+    if starts("account list") {
+        assert_round_trip::<Vec<Subscription>>(&command, stdout);
+    } else if starts("account show") {
+        assert_round_trip::<Subscription>(&command, stdout);
+    } else if starts("account list-locations") {
+        assert_round_trip::<Vec<Location>>(&command, stdout);
+    } else if starts("account tenant list") {
+        assert_round_trip::<Vec<Tenant>>(&command, stdout);
+    } else if starts("group list") {
+        assert_round_trip::<Vec<ResourceGroup>>(&command, stdout);
+    } else if starts("group show") || starts("group create") {
+        assert_round_trip::<ResourceGroup>(&command, stdout);
+    } else if starts("resource list") {
+        assert_round_trip::<Vec<GenericResource>>(&command, stdout);
+    } else if starts("cosmosdb list") {
+        assert_round_trip::<Vec<CosmosAccount>>(&command, stdout);
+    } else if starts("cosmosdb show") {
+        assert_round_trip::<CosmosAccount>(&command, stdout);
+    } else if command.ends_with("--type connection-strings") {
+        assert_round_trip::<CosmosConnectionStrings>(&command, stdout);
+    } else if starts("cosmosdb keys list") {
+        assert_round_trip::<CosmosKeys>(&command, stdout);
+    } else if command.contains(" throughput show ") {
+        assert_round_trip::<ThroughputSettings>(&command, stdout);
+    } else if starts("cosmosdb sql database list")
+        || starts("cosmosdb mongodb database list")
+        || starts("cosmosdb cassandra keyspace list")
+        || starts("cosmosdb gremlin database list")
+    {
+        assert_round_trip::<Vec<CosmosDatabase>>(&command, stdout);
+    } else if starts("cosmosdb sql database show")
+        || starts("cosmosdb cassandra keyspace show")
+        || starts("cosmosdb mongodb database show")
+        || starts("cosmosdb gremlin database show")
+    {
+        assert_round_trip::<CosmosDatabase>(&command, stdout);
+    } else if starts("cosmosdb sql container list") || starts("cosmosdb gremlin graph list") {
+        assert_round_trip::<Vec<CosmosContainer>>(&command, stdout);
+    } else if starts("cosmosdb sql container show") || starts("cosmosdb gremlin graph show") {
+        assert_round_trip::<CosmosContainer>(&command, stdout);
+    } else if starts("cosmosdb sql stored-procedure list") {
+        assert_round_trip::<Vec<StoredProcedure>>(&command, stdout);
+    } else if starts("cosmosdb sql stored-procedure show") {
+        assert_round_trip::<StoredProcedure>(&command, stdout);
+    } else if starts("cosmosdb sql trigger list") {
+        assert_round_trip::<Vec<Trigger>>(&command, stdout);
+    } else if starts("cosmosdb sql trigger show") {
+        assert_round_trip::<Trigger>(&command, stdout);
+    } else if starts("cosmosdb sql user-defined-function list") {
+        assert_round_trip::<Vec<UserDefinedFunction>>(&command, stdout);
+    } else if starts("cosmosdb sql user-defined-function show") {
+        assert_round_trip::<UserDefinedFunction>(&command, stdout);
+    } else if starts("cosmosdb sql role definition list") {
+        assert_round_trip::<Vec<SqlRoleDefinition>>(&command, stdout);
+    } else if starts("cosmosdb sql role definition show") {
+        assert_round_trip::<SqlRoleDefinition>(&command, stdout);
+    } else if starts("cosmosdb sql role assignment list") {
+        assert_round_trip::<Vec<SqlRoleAssignment>>(&command, stdout);
+    } else if starts("cosmosdb sql role assignment show") {
+        assert_round_trip::<SqlRoleAssignment>(&command, stdout);
+    } else if starts("cosmosdb mongodb role definition list") {
+        assert_round_trip::<Vec<MongoRoleDefinition>>(&command, stdout);
+    } else if starts("cosmosdb mongodb role definition show") {
+        assert_round_trip::<MongoRoleDefinition>(&command, stdout);
+    } else if starts("cosmosdb mongodb user definition list") {
+        assert_round_trip::<Vec<MongoUserDefinition>>(&command, stdout);
+    } else if starts("cosmosdb mongodb user definition show") {
+        assert_round_trip::<MongoUserDefinition>(&command, stdout);
+    } else if starts("cosmosdb restorable-database-account list") {
+        assert_round_trip::<Vec<RestorableDatabaseAccount>>(&command, stdout);
+    } else if starts("cosmosdb restorable-database-account show") {
+        assert_round_trip::<RestorableDatabaseAccount>(&command, stdout);
+    } else if starts("cosmosdb sql restorable-database list") {
+        assert_round_trip::<Vec<RestorableSqlDatabase>>(&command, stdout);
+    } else if starts("cosmosdb sql restorable-container list") {
+        assert_round_trip::<Vec<RestorableSqlContainer>>(&command, stdout);
+    } else if starts("cosmosdb sql restorable-resource list") {
+        assert_round_trip::<Vec<RestorableSqlResource>>(&command, stdout);
+    } else if starts("cosmosdb sql retrieve-latest-backup-time") {
+        assert_round_trip::<BackupInformation>(&command, stdout);
+    } else if starts("cosmosdb mongodb collection list") {
+        assert_round_trip::<Vec<MongoCollection>>(&command, stdout);
+    } else if starts("cosmosdb mongodb collection show") {
+        assert_round_trip::<MongoCollection>(&command, stdout);
+    } else if starts("cosmosdb cassandra table list") {
+        assert_round_trip::<Vec<CassandraTable>>(&command, stdout);
+    } else if starts("cosmosdb cassandra table show") {
+        assert_round_trip::<CassandraTable>(&command, stdout);
+    } else if starts("cosmosdb table list") {
+        assert_round_trip::<Vec<CosmosTable>>(&command, stdout);
+    } else if starts("cosmosdb table show") {
+        assert_round_trip::<CosmosTable>(&command, stdout);
+    } else {
+        return false;
+    }
+    true
+}
+
+#[test]
+fn test_fixture_outputs_round_trip() {
+    for fixture in ["account", "cosmos", "mongodb", "cassandra", "gremlin", "table", "throughput", "scripts", "rbac", "restore"] {
+        let fixture = load_fixture(fixture);
+        let checked = fixture.interactions.iter().filter(|interaction| check_interaction(interaction)).count();
+        assert!(checked > 0);
+    }
+}
+
+#[test]
+fn test_top_level_models_serialize_as_camel_case() {
+    let fixture = load_fixture("cosmos");
+    let account: CosmosAccount = serde_json::from_value(fixture.interactions[2].stdout.clone().unwrap()).unwrap();
+
+    let arm = SerializationStyle::Arm.to_value(&account).unwrap();
+    assert!(arm.get("resourceGroup").is_some());
+    assert!(arm.get("documentEndpoint").is_some());
+    assert!(arm["consistencyPolicy"].get("defaultConsistencyLevel").is_some());
+    assert!(arm.get("resource_group").is_none());
+
+    let snake = SerializationStyle::SnakeCase.to_value(&account).unwrap();
+    assert!(snake.get("resource_group").is_some());
+    assert!(snake["consistency_policy"].get("default_consistency_level").is_some());
+    assert!(snake.get("resourceGroup").is_none());
+}
+
+#[test]
+fn test_snake_case_keeps_user_keys() {
+    let container: MongoCollection = serde_json::from_value(json!({
+        "name": "products",
+        "id": "/subscriptions/sub/resourceGroups/rg/providers/Microsoft.DocumentDB/databaseAccounts/a/mongodbDatabases/db/collections/products",
+        "tags": { "costCenter": "retail" },
+        "resource": { "id": "products", "shardKey": { "productCategory": "Hash" }, "_rid": "abc" }
+    }))
+    .unwrap();
+
+    let snake = SerializationStyle::SnakeCase.to_value(&container).unwrap();
+    assert_eq!(snake["tags"]["costCenter"], "retail");
+    assert_eq!(snake["resource"]["shard_key"]["productCategory"], "Hash");
+    assert_eq!(snake["resource"]["_rid"], "abc");
+
+    let json = SerializationStyle::SnakeCase.to_string_pretty(&container).unwrap();
+    let parsed: MongoCollection = SerializationStyle::SnakeCase.parse(&json).unwrap();
+    assert_eq!(parsed.shard_key().and_then(|key| key.get("productCategory")).map(String::as_str), Some("Hash"));
+}
+
+#[test]
+fn test_snake_case_round_trips_user_assigned_identities() {
+    let fixture = load_fixture("cosmos");
+    let mut original = fixture.interactions[2].stdout.clone().unwrap();
+    let identity_id =
+        "/subscriptions/00000000-0000-0000-0000-000000000001/resourceGroups/cosmos-rg/providers/Microsoft.ManagedIdentity/userAssignedIdentities/cosmos_reader";
+    original["identity"] = json!({
+        "principalId": null,
+        "tenantId": null,
+        "type": "UserAssigned",
+        "userAssignedIdentities": {
+            identity_id: { "clientId": "11111111-2222-3333-4444-555555555555", "principalId": "66666666-7777-8888-9999-000000000000" }
+        }
+    });
+    original["futureSetting"] = json!({ "nested_value": { "someFlag": true } });
+    let account: CosmosAccount = serde_json::from_value(original).unwrap();
+
+    let snake = SerializationStyle::SnakeCase.to_value(&account).unwrap();
+    assert_eq!(snake["identity"]["type"], "UserAssigned");
+    assert!(snake["identity"]["user_assigned_identities"][identity_id].get("clientId").is_some());
+    assert_eq!(snake["futureSetting"]["nested_value"]["someFlag"], true);
+
+    let back: CosmosAccount = SerializationStyle::SnakeCase.deserialize(snake).unwrap();
+    assert_eq!(
+        SerializationStyle::Arm.to_value(&back).unwrap(),
+        SerializationStyle::Arm.to_value(&account).unwrap()
+    );
+    let identities = back.identity.as_ref().and_then(|identity| identity.user_assigned_identities.as_ref()).unwrap();
+    assert!(identities.contains_key(identity_id));
+}
+
+#[test]
+fn test_snake_case_keeps_unmodeled_property_names() {
+    let policy: ConsistencyPolicy = serde_json::from_value(json!({
+        "defaultConsistencyLevel": "Session",
+        "some_new_prop": { "inner_key": 1 },
+        "otherProp": true
+    }))
+    .unwrap();
+
+    let snake = SerializationStyle::SnakeCase.to_value(&policy).unwrap();
+    assert_eq!(snake["default_consistency_level"], "Session");
+    assert_eq!(snake["some_new_prop"]["inner_key"], 1);
+    assert_eq!(snake["otherProp"], true);
+
+    let back: ConsistencyPolicy = SerializationStyle::SnakeCase.deserialize(snake).unwrap();
+    assert_eq!(back.extra_field("some_new_prop"), Some(&json!({ "inner_key": 1 })));
+    assert_eq!(back.extra_field("otherProp"), Some(&json!(true)));
+    assert!(back.extra_field("someNewProp").is_none());
+    assert_eq!(SerializationStyle::Arm.to_value(&back).unwrap(), SerializationStyle::Arm.to_value(&policy).unwrap());
+}
+
+#[test]
+fn test_key_conversion() {
+    assert_eq!(camel_to_snake("defaultConsistencyLevel"), "default_consistency_level");
+    assert_eq!(camel_to_snake("_etag"), "_etag");
+    assert_eq!(camel_to_snake("id"), "id");
+    assert_eq!(snake_to_camel("backup_interval_in_minutes"), "backupIntervalInMinutes");
+    assert_eq!(snake_to_camel("_rid"), "_rid");
+}